@@ -1,4 +1,6 @@
 "use strict";
+const promiseUtils = require("../promise-utils");
+
 /**
  * @classdesc Provides [Authenticator]{@link module:auth~Authenticator} instances to be used when connecting to a host.
  * @constructor
@@ -9,7 +11,8 @@ function AuthProvider() {}
 
 /**
  * Returns an [Authenticator]{@link module:auth~Authenticator} instance to be used when connecting to a host.
- * @param {String} endpoint The ip address and port number in the format ip:port
+ * @param {String} [endpoint] The ip address and port number in the format ip:port.
+ * The endpoint is not provided by the driver, and is always undefined.
  * @param {String} name Authenticator name
  * @abstract
  * @returns {Authenticator}
@@ -21,6 +24,58 @@ AuthProvider.prototype.newAuthenticator = function (endpoint, name) {
     );
 };
 
+/**
+ * Creates the function, through which the driver authenticates the connections with this provider.
+ *
+ * Each connection is authenticated by a separate [Authenticator]{@link module:auth~Authenticator},
+ * identified by the id assigned by the driver.
+ * @returns {function(Object): Promise<Buffer|null>}
+ * @internal
+ * @ignore
+ */
+AuthProvider.prototype.getRustConfiguration = function () {
+    /** @type {Map<number, Authenticator>} */
+    const authenticators = new Map();
+    return async (request) => {
+        const id = request.authenticatorId;
+        // Requests other than the initial response are sent only after the authenticator is created.
+        const authenticator = /** @type {Authenticator} */ (
+            authenticators.get(id)
+        );
+        switch (request.kind) {
+            case "initialResponse": {
+                const newAuthenticator = this.newAuthenticator(
+                    undefined,
+                    request.authenticatorName,
+                );
+                authenticators.set(id, newAuthenticator);
+                return (
+                    (await promiseUtils.fromCallback((callback) =>
+                        newAuthenticator.initialResponse(callback),
+                    )) ?? null
+                );
+            }
+            case "evaluateChallenge":
+                return (
+                    (await promiseUtils.fromCallback((callback) =>
+                        authenticator.evaluateChallenge(
+                            request.token ?? undefined,
+                            callback,
+                        ),
+                    )) ?? null
+                );
+            case "onAuthenticationSuccess":
+                authenticator.onAuthenticationSuccess(
+                    request.token ?? undefined,
+                );
+                return null;
+            case "release":
+                authenticators.delete(id);
+                return null;
+        }
+    };
+};
+
 /**
  * @class
  * @classdesc Handles SASL authentication with Cassandra servers.
@@ -20,6 +20,11 @@ const {
 // eslint-disable-next-line no-unused-vars
 const { ExecutionProfile } = require("./execution-profile.js");
 
+// TCP keep-alive delay used when `socketOptions.keepAliveDelay` is 0, in milliseconds.
+// Idle connections send the keep-alive probes early enough to outlive the idle timeouts
+// of most firewalls and load balancers.
+const defaultKeepAliveDelay = 60000;
+
 /**
  * Client options.
  *
@@ -86,17 +91,15 @@ const { ExecutionProfile } = require("./execution-profile.js");
  * the coordinator per query.
  * @property {RetryPolicy} [policies.retry] The retry policy.
  * @property {ReconnectionPolicy} [policies.reconnection] The reconnection policy to be used.
- * [TODO: Add support for this field]
  * @property {AddressTranslator} [policies.addressResolution] The address resolution policy.
  * [TODO: Add support for this field]
  * @property {SpeculativeExecutionPolicy} [policies.speculativeExecution] The `SpeculativeExecutionPolicy`
  * instance to be used to determine if the client should send speculative queries when the selected host takes more
- * time than expected.
+ * time than expected. Only idempotent statements are executed speculatively.
  *
  * Default: `[NoSpeculativeExecutionPolicy]{@link
  * module:policies/speculativeExecution~NoSpeculativeExecutionPolicy}`
  *
- * [TODO: Add support for this field]
  * @property {TimestampGenerator} [policies.timestampGeneration] The client-side
  * [query timestamp generator]{@link module:policies/timestampGeneration~TimestampGenerator}.
  *
@@ -104,17 +107,22 @@ const { ExecutionProfile } = require("./execution-profile.js");
  *
  * Use `null` to disable client-side timestamp generation.
  *
- * [TODO: Add support for this field]
+ * The timestamps are generated by the driver for every query, prepared statement execution and batch
+ * that does not have the `timestamp` option set.
+ *
  * @property {QueryOptions} [queryOptions] Default options for all queries.
  * [TODO: Add support for this field]
  * @property {Object} [pooling] Pooling options.
- * [TODO: Add support for this field]
  * @property {Number} [pooling.heartBeatInterval] The amount of idle time in milliseconds that has to pass before the
- * driver issues a request on an active connection to avoid idle time disconnections. Default: 30000.
- * [TODO: Add support for this field]
+ * driver issues a request on an active connection to avoid idle time disconnections. Use 0 to disable heartbeats.
+ * Default: 30000.
  * @property {Object} [pooling.coreConnectionsPerHost] Associative array containing amount of connections per host
- * distance.
- * [TODO: Add support for this field]
+ * distance. The driver keeps pools of the same size for all the hosts, so only the value for the
+ * `local` distance is used. On ScyllaDB, the connections are spread across the shards of the host.
+ * Cannot be used together with `pooling.coreConnectionsPerShard`.
+ * @property {Number} [pooling.coreConnectionsPerShard] The amount of connections to each shard of a host.
+ * Cassandra hosts are treated as hosts with a single shard. Cannot be used together with
+ * `pooling.coreConnectionsPerHost`. When neither is provided, one connection per shard is kept.
  * @property {Number} [pooling.maxRequestsPerConnection] The maximum number of requests per connection. The default
  * value is:
  * - For modern protocol versions (v3 and above): 2048
@@ -125,46 +133,40 @@ const { ExecutionProfile } = require("./execution-profile.js");
  * connect. Default: true.
  * [TODO: Add support for this field]
  * @property {Object} [protocolOptions]
- * [TODO: Add support for this field]
  * @property {Number} [protocolOptions.port] The port to use to connect to the Cassandra host. If not set through this
- * method, the default port (9042) will be used instead.
- * [TODO: Add support for this field]
+ * method, the default port (9042) will be used instead. It is applied to the contact points that do not specify a port.
  * @property {Number} [protocolOptions.maxSchemaAgreementWaitSeconds] The maximum time in seconds to wait for schema
- * agreement between nodes before returning from a DDL query. Default: 10.
- * [TODO: Add support for this field]
+ * agreement between nodes before returning from a DDL query. Use 0 to disable waiting for schema agreement.
+ * Default: 10.
+ * @property {Number} [protocolOptions.schemaAgreementInterval] The interval in milliseconds between the checks
+ * of schema agreement between nodes, when waiting for it. Default: 200.
  * @property {Number} [protocolOptions.maxVersion] When set, it limits the maximum protocol version used to connect to
  * the nodes.
  * Useful for using the driver against a cluster that contains nodes with different major/minor versions of Cassandra.
  * [TODO: Add support for this field]
  * @property {Object} [socketOptions]
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.connectTimeout] Connection timeout in milliseconds. Default: 5000.
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.defunctReadTimeoutThreshold] Determines the amount of requests that simultaneously
  * have to timeout before closing the connection. Default: 64.
  * [TODO: Add support for this field]
  * @property {Boolean} [socketOptions.keepAlive] Whether to enable TCP keep-alive on the socket. Default: true.
- * [TODO: Add support for this field]
- * @property {Number} [socketOptions.keepAliveDelay] TCP keep-alive delay in milliseconds. Default: 0.
- * [TODO: Add support for this field]
- * @property {Number} [socketOptions.readTimeout] Per-host read timeout in milliseconds.
+ * @property {Number} [socketOptions.keepAliveDelay] TCP keep-alive delay in milliseconds: the amount of time
+ * a connection has to be idle before the keep-alive probes are sent. Default: 0.
  *
- * Please note that this is not the maximum time a call to {@link Client#execute} may have to wait;
- * this is the maximum time that call will wait for one particular Cassandra host, but other hosts will be tried if
- * one of them timeout. In other words, a {@link Client#execute} call may theoretically wait up to
- * `readTimeout * number_of_cassandra_hosts` (though the total number of hosts tried for a given query also
- * depends on the LoadBalancingPolicy in use).
+ * With a delay of 0, the driver uses a delay of 60 seconds, so that idle connections are not dropped
+ * by firewalls and load balancers. Ignored when `keepAlive` is `false`.
+ * @property {Number} [socketOptions.readTimeout] Request timeout in milliseconds.
  *
- * When setting this value, keep in mind the following:
- * - the timeout settings used on the Cassandra side (*_request_timeout_in_ms in cassandra.yaml) should be taken
- * into account when picking a value for this read timeout. You should pick a value a couple of seconds greater than
- * the Cassandra timeout settings.
- * - the read timeout is only approximate and only control the timeout to one Cassandra host, not the full query.
+ * Unlike in the DataStax driver, this is not a per-host timeout: it is the maximum time a call to
+ * {@link Client#execute} will wait for the whole request, including all retries on other hosts.
+ * When it is exceeded, the call fails with an error named `OperationTimedOutError`.
  *
- * Setting a value of 0 disables read timeouts. Default: `12000`.
- * [TODO: Add support for this field]
+ * When setting this value, keep in mind that the timeout settings used on the server side
+ * (*_request_timeout_in_ms in cassandra.yaml) should be taken into account when picking a value for this timeout.
+ * You should pick a value a couple of seconds greater than the server timeout settings.
+ *
+ * Setting a value of 0 disables request timeouts. Default: `12000`.
  * @property {Boolean} [socketOptions.tcpNoDelay] When set to true, it disables the Nagle algorithm. Default: true.
- * [TODO: Add support for this field]
  * @property {Number} [socketOptions.coalescingThreshold] Buffer length in bytes use by the write queue before flushing
  * the frames. Default: 8000.
  * [TODO: Add support for this field]
@@ -906,6 +908,80 @@ function setMetadataDependent(client) {
     );
 }
 
+/**
+ * @param {policies.loadBalancing.LoadBalancingPolicy} policy
+ * @returns {rust.LoadBalancingConfig}
+ */
+function loadBalancingIntoRust(policy) {
+    try {
+        return policy.getRustConfiguration();
+    } catch (e) {
+        // We will catch this error when:
+        //  - The policy does not implement getRustConfiguration (someone provided policy that does not inherit from LoadBalancingPolicy)
+        //  - The policy implements getRustConfiguration but throws "Currently this policy is not supported by the driver"
+        //  - Some other obscure error, like node deciding it has a bad day and just wants to crash
+        throw new Error(
+            `This load balancing policy (${policy.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
+        );
+    }
+}
+
+/**
+ * @param {policies.retry.RetryPolicy} policy
+ * @returns {rust.RetryPolicyConfig}
+ */
+function retryIntoRust(policy) {
+    try {
+        return policy.getRustConfiguration();
+    } catch (e) {
+        throw new Error(
+            `This retry policy (${policy.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
+        );
+    }
+}
+
+/**
+ * @param {policies.speculativeExecution.SpeculativeExecutionPolicy} policy
+ * @returns {rust.SpeculativeExecutionConfig|undefined}
+ */
+function speculativeExecutionIntoRust(policy) {
+    try {
+        return policy.getRustConfiguration();
+    } catch (e) {
+        throw new Error(
+            `This speculative execution policy (${policy.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
+        );
+    }
+}
+
+/**
+ * @param {policies.reconnection.ReconnectionPolicy} policy
+ * @returns {rust.ReconnectionPolicyConfig}
+ */
+function reconnectionIntoRust(policy) {
+    try {
+        return policy.getRustConfiguration();
+    } catch (e) {
+        throw new Error(
+            `This reconnection policy (${policy.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
+        );
+    }
+}
+
+/**
+ * @param {policies.timestampGeneration.TimestampGenerator} generator
+ * @returns {rust.TimestampGeneratorConfig}
+ */
+function timestampGeneratorIntoRust(generator) {
+    try {
+        return generator.getRustConfiguration();
+    } catch (e) {
+        throw new Error(
+            `This timestamp generator (${generator.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
+        );
+    }
+}
+
 /**
  * Create rust options using js Client options
  * @param {ClientOptions} options
@@ -936,39 +1012,35 @@ function setRustOptions(options) {
             rustOptions.credentialsUsername = options.authProvider.username;
             rustOptions.credentialsPassword = options.authProvider.password;
         } else if (!(options.authProvider instanceof auth.NoAuthProvider)) {
-            throw new errors.ArgumentError(
-                // TODO: Add support for other auth providers
-                "Unsupported auth provider: " + options.authProvider,
-            );
+            // Custom providers are called by the driver for each connection.
+            rustOptions.authenticate =
+                options.authProvider.getRustConfiguration();
         }
     }
 
     if (options.policies) {
         if (options.policies.loadBalancing) {
-            try {
-                rustOptions.loadBalancingConfig =
-                    options.policies.loadBalancing.getRustConfiguration();
-            } catch (e) {
-                // We will catch this error when:
-                //  - The policy does not implement getRustConfiguration (someone provided policy that does not inherit from LoadBalancingPolicy)
-                //  - The policy implements getRustConfiguration but throws "Currently this policy is not supported by the driver"
-                //  - Some other obscure error, like node deciding it has a bad day and just wants to crash
-                throw new Error(
-                    `This load balancing policy (${options.policies.loadBalancing.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
-                );
-            }
+            rustOptions.loadBalancingConfig = loadBalancingIntoRust(
+                options.policies.loadBalancing,
+            );
         }
         if (options.policies.retry) {
-            try {
-                rustOptions.retryPolicy =
-                    options.policies.retry.getRustConfiguration();
-            } catch (e) {
-                // Compared to load balancing policy, retry policies do not have "empty" base class. For this reason, we check there for derived classes,
-                // to ensure that we do not accept custom policies, instead of treating them as a base class quietly causing unexpected behavior.
-                throw new Error(
-                    `This retry policy (${options.policies.retry.constructor.name}) does not appear to be supported by the driver. Root cause: ${e.message}`,
-                );
-            }
+            rustOptions.retryPolicy = retryIntoRust(options.policies.retry);
+        }
+        if (options.policies.speculativeExecution) {
+            rustOptions.speculativeExecution = speculativeExecutionIntoRust(
+                options.policies.speculativeExecution,
+            );
+        }
+        if (options.policies.reconnection) {
+            rustOptions.reconnectionPolicy = reconnectionIntoRust(
+                options.policies.reconnection,
+            );
+        }
+        if (options.policies.timestampGeneration) {
+            rustOptions.timestampGenerator = timestampGeneratorIntoRust(
+                options.policies.timestampGeneration,
+            );
         }
         if (options.policies.addressResolution) {
             switch (options.policies.addressResolution.constructor) {
@@ -994,7 +1066,50 @@ function setRustOptions(options) {
         rustOptions.sslOptions = normalizeSslOptions(options.sslOptions);
     }
 
+    if (options.socketOptions) {
+        rustOptions.requestTimeout = options.socketOptions.readTimeout;
+        rustOptions.connectTimeout = options.socketOptions.connectTimeout;
+        rustOptions.tcpNodelay = options.socketOptions.tcpNoDelay;
+        if (options.socketOptions.keepAlive) {
+            rustOptions.tcpKeepaliveInterval =
+                options.socketOptions.keepAliveDelay || defaultKeepAliveDelay;
+        }
+    }
+
+    if (options.pooling) {
+        rustOptions.keepaliveInterval = options.pooling.heartBeatInterval;
+        if (options.pooling.coreConnectionsPerHost) {
+            rustOptions.connectionsPerHost =
+                options.pooling.coreConnectionsPerHost[types.distance.local];
+        }
+        rustOptions.connectionsPerShard =
+            options.pooling.coreConnectionsPerShard;
+    }
+
+    if (options.profiles) {
+        rustOptions.executionProfiles = options.profiles.map((profile) => ({
+            name: profile.name,
+            consistency: profile.consistency,
+            serialConsistency: profile.serialConsistency,
+            requestTimeout: profile.readTimeout,
+            loadBalancingConfig:
+                profile.loadBalancing &&
+                loadBalancingIntoRust(profile.loadBalancing),
+            retryPolicy: profile.retry && retryIntoRust(profile.retry),
+        }));
+    }
+
     if (options.protocolOptions) {
+        rustOptions.defaultPort = options.protocolOptions.port;
+        if (
+            typeof options.protocolOptions.maxSchemaAgreementWaitSeconds ===
+            "number"
+        ) {
+            rustOptions.schemaAgreementTimeout =
+                options.protocolOptions.maxSchemaAgreementWaitSeconds * 1000;
+        }
+        rustOptions.schemaAgreementInterval =
+            options.protocolOptions.schemaAgreementInterval;
         if (options.protocolOptions.noCompact !== undefined) {
             // This option was present in the DSx driver, but is no longer relevant.
             // We explicitly check for it to inform users using this options,
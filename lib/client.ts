@@ -41,6 +41,9 @@ import type {
 } from "../";
 
 const { ProfileManager } = executionProfile;
+// lib/metadata/index.d.ts describes only the public interface of the metadata module,
+// so the implementation is loaded without going through those type definitions.
+const Metadata: new (client: Client) => metadataModule.Metadata = require("./metadata");
 const description = packageInfo.description;
 const { version } = packageInfo;
 
@@ -97,6 +100,11 @@ class Client extends events.EventEmitter {
     rustClient: rust.SessionWrapper | undefined;
     #encoder: Encoder;
     #loggingId: number | undefined;
+    /**
+     * Hosts of the cluster, as of the last changes of the cluster passed from Rust.
+     * Used to find the removed hosts, which are no longer part of the cluster.
+     */
+    #knownHosts: HostMap | undefined;
 
     /**
      * @internal
@@ -126,15 +134,13 @@ class Client extends events.EventEmitter {
     isShuttingDown: boolean;
     /**
      * Gets the schema and cluster metadata information.
-     * TODO: This field is currently not supported
      */
-    metadata: metadataModule.Metadata | undefined;
+    metadata: metadataModule.Metadata;
     /**
      * The [ClientMetrics]{@link module:metrics~ClientMetrics} instance used to expose measurements of its internal
      * behavior and of the server as seen from the driver side.
      *
      * By default, a [DefaultMetrics]{@link module:metrics~DefaultMetrics} instance is used.
-     * TODO: This field is currently not supported
      */
     metrics: metricsModule.ClientMetrics | undefined;
 
@@ -150,6 +156,17 @@ class Client extends events.EventEmitter {
         );
 
         this.rustOptions = clientOptions.setRustOptions(this.options);
+        if (this.options.requestTracker) {
+            this.rustOptions.requestTracker =
+                this.options.requestTracker.getRustConfiguration(this);
+        }
+        this.rustOptions.metricsEvents =
+            this.options.metrics.getRustConfiguration(this);
+        // We need weak ref, since it lives inside the callback held by the Rust session.
+        // If it was normal reference, it would block GC of this client instance.
+        const weakThis = new WeakRef(this);
+        this.rustOptions.clusterEvents = (events: rust.ClusterEventObj[]) =>
+            weakThis.deref()?.#onClusterEvents(events);
 
         Object.defineProperty(this, "profileManager", {
             value: new ProfileManager(this.options),
@@ -158,9 +175,9 @@ class Client extends events.EventEmitter {
         this.setMaxListeners(0);
         this.connected = false;
         this.isShuttingDown = false;
-        this.metadata = undefined;
+        this.metadata = new Metadata(this);
 
-        this.metrics = undefined;
+        this.metrics = this.options.metrics;
 
         // TODO: This field is currently hardcoded. Should be implemented properly
         this.#encoder = new Encoder(0x04, this.options);
@@ -186,6 +203,15 @@ class Client extends events.EventEmitter {
      * - {@link Host host} The host that changed the status.
      * @event Client#hostDown
      */
+    /**
+     * Emitted when the schema of the cluster changed.
+     * - `change` Object with the following properties:
+     *   - `change`: `'created'`, `'updated'` or `'dropped'`.
+     *   - `target`: `'keyspace'`, `'table'`, `'view'` or `'udt'`.
+     *   - `keyspace`: Name of the keyspace.
+     *   - `name`: Name of the changed element of the keyspace, `null` for keyspace changes.
+     * @event Client#schemaChange
+     */
 
     /**
      * Gets the name of the active keyspace.
@@ -236,12 +262,19 @@ class Client extends events.EventEmitter {
 
     /**
      * Manually prepare query into prepared statement.
+     * @param keyspace The keyspace to prepare the statement in. Defaults to the keyspace of the client.
      * @internal
      * @ignore
      */
-    async prepareStatement(statement: string): Promise<PreparedInfo> {
+    async prepareStatement(
+        statement: string,
+        keyspace?: string,
+    ): Promise<PreparedInfo> {
         // This will be called only after checking that client is connected
-        let expectedTypes = await this.rustClient!.prepareStatement(statement);
+        let expectedTypes = await this.rustClient!.prepareStatement(
+            statement,
+            keyspace,
+        );
         let types = expectedTypes.map((t) => convertComplexType(t[0]));
         let boundParamNames = expectedTypes.map((t) => t[1].toLowerCase());
         return new PreparedInfo(types, statement, boundParamNames);
@@ -337,6 +370,8 @@ class Client extends events.EventEmitter {
             this.rustClient = await rust.SessionWrapper.createSession(
                 this.rustOptions,
             );
+            this.#knownHosts = this.hosts;
+            await this.profileManager.init(this, this.#knownHosts);
         } catch (err) {
             // We should close the pools (if any) and reset the state to allow successive calls to connect()
             this.connected = false;
@@ -351,6 +386,30 @@ class Client extends events.EventEmitter {
         this.emit("connected");
     }
 
+    /**
+     * Emits the events of the changes of the cluster, detected by the Rust driver.
+     */
+    #onClusterEvents(events: rust.ClusterEventObj[]) {
+        const previousHosts = this.#knownHosts;
+        const hosts = this.hosts;
+        this.#knownHosts = hosts;
+        for (const event of events) {
+            if (event.kind === "schemaChange") {
+                const { kind, ...change } = event;
+                this.emit(kind, change);
+                continue;
+            }
+            const hostId = types.Uuid.fromString(event.hostId);
+            // Removed hosts are no longer part of the cluster.
+            const host = (
+                event.kind === "hostRemove" ? previousHosts : hosts
+            )?.get(hostId);
+            if (host) {
+                this.emit(event.kind, host);
+            }
+        }
+    }
+
     /**
      * Executes a query on an available connection.
      *
@@ -474,6 +533,7 @@ class Client extends events.EventEmitter {
             resultTuple = await this.#rustyExecutePrepared(
                 query,
                 params,
+                execOptions,
                 rustOptions,
                 paged,
                 pageState,
@@ -515,6 +575,7 @@ class Client extends events.EventEmitter {
     async #rustyExecutePrepared(
         query: string | PreparedInfo,
         params: ArrayOrObject,
+        execOptions: ExecutionOptions,
         rustOptions: rust.QueryOptionsWrapper,
         paged: boolean,
         pageState?: rust.PagingStateWrapper,
@@ -525,7 +586,10 @@ class Client extends events.EventEmitter {
 
         switch (true) {
             case typeof query === "string":
-                prepared = await this.prepareStatement(query);
+                prepared = await this.prepareStatement(
+                    query,
+                    execOptions.getKeyspace(),
+                );
                 break;
             case query instanceof PreparedInfo:
                 prepared = query;
@@ -874,7 +938,10 @@ class Client extends events.EventEmitter {
             if (shouldBePrepared) {
                 let prepared = preparedCache.getElement(statement);
                 if (!prepared) {
-                    prepared = await this.prepareStatement(statement);
+                    prepared = await this.prepareStatement(
+                        statement,
+                        execOptions.getKeyspace(),
+                    );
                     preparedCache.storeElement(statement, prepared);
                 }
                 types = prepared.types;
@@ -910,10 +977,31 @@ class Client extends events.EventEmitter {
 
     /**
      * Gets the host that are replicas of a given token.
+     *
+     * See [Metadata#getReplicas()]{@link module:metadata~Metadata#getReplicas} for details.
      */
-    getReplicas(keyspace: string, token: Buffer): Array<Host> {
-        throw new Error(`TODO: Not implemented`);
-        // return this.metadata.getReplicas(keyspace, token);
+    getReplicas(
+        keyspace: string,
+        token: Buffer,
+        tableName?: string,
+    ): Array<Host> | null {
+        return this.metadata.getReplicas(keyspace, token, tableName);
+    }
+
+    /**
+     * Gets the metrics measured by the driver: the numbers of requests, errors, retries and timeouts,
+     * the rates of the requests and the latency percentiles, in milliseconds.
+     *
+     * Metrics of each node of the cluster are keyed by the host id of the node.
+     * Returns `undefined` when the client is not connected.
+     */
+    getDriverMetrics(): metricsModule.DriverMetrics | undefined {
+        if (!this.rustClient) return undefined;
+        const { nodes, ...metrics } = this.rustClient.metrics;
+        return {
+            ...metrics,
+            nodes: Object.fromEntries(nodes.map((node) => [node.hostId, node])),
+        };
     }
 
     /**
@@ -970,6 +1058,9 @@ class Client extends events.EventEmitter {
         this.connected = false;
         this.isShuttingDown = true;
 
+        if (this.options.requestTracker) {
+            this.options.requestTracker.shutdown();
+        }
         this.#closeLogging();
     }
 
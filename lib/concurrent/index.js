@@ -165,7 +165,10 @@ class ArrayBasedExecutor {
         try {
             let prepared = this.#cache.getElement(query);
             if (!prepared) {
-                prepared = await (this.#client.prepareStatement(query));
+                prepared = await (this.#client.prepareStatement(
+                    query,
+                    this.#queryOptions.getKeyspace(),
+                ));
                 this.#cache.storeElement(query, prepared);
             }
             await this.#client
@@ -1,4 +1,6 @@
 "use strict";
+import rust = require("../index");
+
 /**
  * Contains the error classes exposed by the driver.
  * @module errors
@@ -180,3 +182,10 @@ export {
     NotSupportedError,
     ResponseError,
 };
+
+// Registers the constructors of the errors that the errors of the Rust driver are classified into,
+// so that Rust can construct them directly, and they can be told apart with `instanceof`.
+rust.registerResponseErrorCtor(ResponseError);
+rust.registerNoHostAvailableErrorCtor(NoHostAvailableError);
+rust.registerOperationTimedOutErrorCtor(OperationTimedOutError);
+rust.registerArgumentErrorCtor(ArgumentError);
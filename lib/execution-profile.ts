@@ -113,8 +113,12 @@ export class ProfileManager {
 
     async init(client: Client, hosts: HostMap): Promise<void> {
         for (const lbp of this.#loadBalancingPolicies) {
+            // Policies implemented in Rust are configured when the session is created.
+            if (!lbp.hasJsQueryPlan()) {
+                continue;
+            }
             await promiseUtils.fromCallback((callback: EmptyCallback) =>
-                lbp.init(client, hosts, callback),
+                lbp.initJsQueryPlan(client, hosts, callback),
             );
         }
     }
@@ -35,11 +35,6 @@ class Host extends events.EventEmitter {
      */
     address: net.SocketAddress;
 
-    /**
-     * Gets string containing the Cassandra version.
-     */
-    cassandraVersion: string | null;
-
     /**
      * Gets data center name of the node.
      */
@@ -62,6 +57,8 @@ class Host extends events.EventEmitter {
      */
     hostId: Uuid;
 
+    #state: rust.HostStateWrapper;
+
     /**
      * Creates a new Host instance.
      *
@@ -74,53 +71,69 @@ class Host extends events.EventEmitter {
         datacenter: string | null,
         rack: string | null,
         hostId: Buffer,
+        state: rust.HostStateWrapper,
+        tokens: string[],
     ) {
         super();
         this.address = address;
-        this.cassandraVersion = null;
         this.datacenter = datacenter;
         this.rack = rack;
-        this.tokens = [];
+        this.tokens = tokens;
         this.hostId = Uuid.fromRust(hostId);
+        this.#state = state;
+    }
+
+    /**
+     * Gets string containing the Cassandra version (the release version, for ScyllaDB nodes).
+     *
+     * The version is read from the node in the background, the first time it is requested
+     * after the driver connects to the node, so it is `null` until then.
+     */
+    get cassandraVersion(): string | null {
+        return this.#state.releaseVersion();
     }
 
     /**
-     * This endpoint is not yet implemented, and its usage will throw an error
+     * Gets the number of shards of the node.
      *
+     * It is `null` for nodes that are not ScyllaDB nodes, or when the driver is not connected to the node.
+     */
+    get shardCount(): number | null {
+        return this.#state.shardCount();
+    }
+
+    /**
      * Determines if the node is UP now (seen as UP by the driver).
+     *
+     * A node is seen as UP, when the driver has any open connection to it.
      */
     isUp(): boolean {
-        throw new Error(`TODO: Not implemented`);
+        return this.#state.isUp();
     }
 
     /**
-     * This endpoint is not yet implemented, and its usage will throw an error
-     *
      * Determines if the host can be considered as UP.
      * Deprecated: Use {@link Host#isUp()} instead.
      */
     canBeConsideredAsUp(): boolean {
-        throw new Error(`TODO: Not implemented`);
+        return this.isUp();
     }
 
     /**
-     * This endpoint is not yet implemented, and its usage will throw an error
-     *
      * Returns an array containing the Cassandra Version as an Array of Numbers having the major version in the first
      * position.
+     *
+     * The version is read from the node in the background, the first time it is requested
+     * after the driver connects to the node, so it is empty until then.
      */
     getCassandraVersion(): number[] {
-        // We never set the version when creating object from Rust,
-        // so we will explicitly throw an error, when someone attempts to get the version
-        // to avoid any confusion
-        throw new Error(`TODO: Not implemented`);
-        // if (!this.cassandraVersion) {
-        //     return utils.emptyArray;
-        // }
-        // return this.cassandraVersion
-        //     .split("-")[0]
-        //     .split(".")
-        //     .map((x) => parseInt(x, 10));
+        if (!this.cassandraVersion) {
+            return [];
+        }
+        return this.cassandraVersion
+            .split("-")[0]
+            .split(".")
+            .map((x) => parseInt(x, 10));
     }
 
     /**
@@ -68,6 +68,7 @@ export enum ColumnKind {
   Static = 1,
   ClusteringKey = 2,
   PartitionKey = 3,
+  Other = 4,
 }
 
 export interface ColumnMetadata {
@@ -94,7 +95,8 @@ export interface Index {
   isKeysKind(): boolean;
 }
 
-export interface MaterializedView extends TableMetadata {
+export interface MaterializedView {
+  viewMetadata: TableMetadata;
   tableName: string;
 }
 
@@ -108,16 +110,16 @@ export interface TableMetadata {
 export interface QueryTrace {
   requestType: string;
   coordinator: InetAddress;
-  parameters: { [key: string]: any };
-  startedAt: number | types.Long;
+  parameters: { [key: string]: string };
+  startedAt: Date;
   duration: number;
-  clientAddress: string;
+  clientAddress: InetAddress;
   events: Array<{
-    id: Uuid;
-    activity: any;
-    source: any;
-    elapsed: any;
-    thread: any;
+    id: types.TimeUuid;
+    activity: string;
+    source: InetAddress;
+    elapsed: number;
+    thread: string;
   }>;
 }
 
@@ -207,12 +209,21 @@ export interface Metadata {
   getTrace(
     traceId: Uuid,
     consistency?: types.consistencies,
-  ): QueryTrace | null;
+  ): Promise<QueryTrace>;
+
+  getTrace(traceId: Uuid, callback: ValueCallback<QueryTrace>): void;
+
+  getTrace(
+    traceId: Uuid,
+    consistency: types.consistencies,
+    callback: ValueCallback<QueryTrace>,
+  ): void;
 
   getReplicas(
     keyspaceName: string,
     token: Buffer | token.Token | token.TokenRange,
-  ): Host[];
+    tableName?: string,
+  ): Host[] | null;
 
   getTokenRanges(): Set<token.TokenRange>;
 
@@ -225,5 +236,11 @@ export interface Metadata {
 
   newTokenRange(start: token.Token, end: token.Token): token.TokenRange;
 
-  checkSchemaAgreement(): Boolean;
+  checkSchemaAgreement(): Promise<boolean>;
+
+  checkSchemaAgreement(callback: ValueCallback<boolean>): void;
+
+  awaitSchemaAgreement(): Promise<Uuid>;
+
+  awaitSchemaAgreement(callback: ValueCallback<Uuid>): void;
 }
@@ -1,22 +1,21 @@
 "use strict";
 
+const _rust = require("../../index");
+const { convertComplexType } = require("../types/cql-utils");
+const { KeyspaceMetadata, Strategy } = require("./keyspace-metadata");
+const { TableMetadata, ColumnMetadata } = require("./table-metadata");
+const { MaterializedView } = require("./materialized-view");
+const { Udt, UdtField } = require("./user-defined-type");
+const { TokenRange } = require("../token");
+const { Murmur3Tokenizer } = require("../tokenizer");
+const { Uuid, TimeUuid, InetAddress } = require("../types");
+const promiseUtils = require("../promise-utils");
+
 /**
  * Module containing classes and fields related to metadata.
  * @module metadata
  */
 
-/**
- * @const
- * @private
- */
-const _selectTraceSession =
-    "SELECT * FROM system_traces.sessions WHERE session_id=%s";
-/**
- * @const
- * @private
- */
-const _selectTraceEvents =
-    "SELECT * FROM system_traces.events WHERE session_id=%s";
 /**
  * @const
  * @private
@@ -27,28 +26,173 @@ const _selectSchemaVersionPeers = "SELECT schema_version FROM system.peers";
  * @private
  */
 const _selectSchemaVersionLocal = "SELECT schema_version FROM system.local";
+
 /**
- * @const
+ * @param {string} [address]
+ * @returns {InetAddress|null}
  * @private
  */
-const _traceMaxAttemps = 5;
+function toInetAddress(address) {
+    return address != null ? InetAddress.fromString(address) : null;
+}
+
 /**
- * @const
+ * Returns the own property `key` of `obj`, ignoring properties inherited from the prototype.
+ * @template T
+ * @param {Record<string, T>} obj
+ * @param {string} key
+ * @returns {T | undefined}
  * @private
  */
-const _traceAttemptDelay = 400;
+function getOwn(obj, key) {
+    return Object.hasOwn(obj, key) ? obj[key] : undefined;
+}
 
 /**
  * Represents cluster and schema information.
  * The metadata class acts as a internal state of the driver.
  */
 class Metadata {
+    /**
+     * @type {InstanceType<typeof import("../client")>}
+     */
+    #client;
+
+    /**
+     * Already converted metadata objects, keyed by the raw objects received from Rust.
+     *
+     * Rust returns the same raw objects for as long as the cluster state does not change,
+     * so this avoids converting the same schema multiple times. Once Rust drops
+     * an outdated snapshot, its raw objects (and the converted ones) can be garbage collected.
+     * @type {WeakMap<object, any>}
+     */
+    #converted = new WeakMap();
+
+    #tokenizer = new Murmur3Tokenizer();
+
     /**
      * Creates a new instance of {@link Metadata}.
-     * @param {ClientOptions} options
-     * @param {ControlConnection} controlConnection Control connection used to retrieve information.
+     * @param {InstanceType<typeof import("../client")>} client Client this metadata belongs to.
+     * @internal
+     * @ignore
+     */
+    constructor(client) {
+        this.#client = client;
+    }
+
+    /**
+     * Returns the raw schema metadata of all keyspaces, as received from Rust.
+     * Before the client is connected, no keyspaces are known.
+     * @returns {Record<string, _rust.KeyspaceMetadataObj>}
+     */
+    #getRawKeyspaces() {
+        const rustClient = this.#client.rustClient;
+        if (!rustClient) {
+            return {};
+        }
+        return rustClient.getKeyspaces();
+    }
+
+    /**
+     * Converts the raw object with `converter`, or returns the result of a previous conversion
+     * of the very same raw object.
+     * @template R, T
+     * @param {R} raw
+     * @param {(raw: R) => T} converter
+     * @returns {T}
+     */
+    #convert(raw, converter) {
+        let result = this.#converted.get(/** @type {object} */ (raw));
+        if (result === undefined) {
+            result = converter(raw);
+            this.#converted.set(/** @type {object} */ (raw), result);
+        }
+        return result;
+    }
+
+    /**
+     * @param {_rust.TableMetadataObj} raw
+     * @returns {TableMetadata}
+     */
+    #convertTable(raw) {
+        return this.#convert(raw, (raw) =>
+            Object.assign(new TableMetadata(), {
+                columns: Object.fromEntries(
+                    Object.entries(raw.columns).map(([name, column]) => [
+                        name,
+                        Object.assign(new ColumnMetadata(), {
+                            type: convertComplexType(column.type),
+                            kind: column.kind,
+                        }),
+                    ]),
+                ),
+                partitionKey: raw.partitionKey,
+                clusteringKey: raw.clusteringKey,
+                partitioner: raw.partitioner,
+            }),
+        );
+    }
+
+    /**
+     * @param {_rust.MaterializedViewObj} raw
+     * @returns {MaterializedView}
+     */
+    #convertView(raw) {
+        return this.#convert(raw, (raw) =>
+            Object.assign(new MaterializedView(), {
+                viewMetadata: this.#convertTable(raw.viewMetadata),
+                tableName: raw.tableName,
+            }),
+        );
+    }
+
+    /**
+     * @param {_rust.UdtObj} raw
+     * @returns {Udt}
+     */
+    #convertUdt(raw) {
+        return this.#convert(raw, (raw) =>
+            Object.assign(new Udt(), {
+                name: raw.name,
+                keyspace: raw.keyspace,
+                fields: raw.fields.map((field) =>
+                    Object.assign(new UdtField(), {
+                        name: field.name,
+                        type: convertComplexType(field.type),
+                    }),
+                ),
+            }),
+        );
+    }
+
+    /**
+     * @param {_rust.KeyspaceMetadataObj} raw
+     * @returns {KeyspaceMetadata}
      */
-    constructor(options, controlConnection) {
+    #convertKeyspace(raw) {
+        /**
+         * @template R, T
+         * @param {Record<string, R>} items
+         * @param {(raw: R) => T} converter
+         * @returns {Record<string, T>}
+         */
+        const convertAll = (items, converter) =>
+            Object.fromEntries(
+                Object.entries(items).map(([name, item]) => [
+                    name,
+                    converter(item),
+                ]),
+            );
+
+        return this.#convert(raw, (raw) =>
+            Object.assign(new KeyspaceMetadata(), {
+                strategy: Object.assign(new Strategy(), raw.strategy),
+                durableWrites: raw.durableWrites,
+                tables: convertAll(raw.tables, (t) => this.#convertTable(t)),
+                views: convertAll(raw.views, (v) => this.#convertView(v)),
+                udts: convertAll(raw.udts, (u) => this.#convertUdt(u)),
+            }),
+        );
     }
 
     /**
@@ -57,7 +201,8 @@ class Metadata {
      * @returns {KeyspaceMetadata | null} The keyspace metadata, or `null` if it does not exist.
      */
     getKeyspace(name) {
-        throw new Error("TODO: Not implemented");
+        const raw = getOwn(this.#getRawKeyspaces(), name);
+        return raw ? this.#convertKeyspace(raw) : null;
     }
 
     /**
@@ -65,7 +210,12 @@ class Metadata {
      * @returns {Map<string, KeyspaceMetadata>} A map of all keyspaces indexed by name.
      */
     getKeyspaces() {
-        throw new Error("TODO: Not implemented");
+        return new Map(
+            Object.entries(this.#getRawKeyspaces()).map(([name, raw]) => [
+                name,
+                this.#convertKeyspace(raw),
+            ]),
+        );
     }
 
     /**
@@ -73,12 +223,37 @@ class Metadata {
      *
      * It uses the pre-loaded keyspace metadata to retrieve the replicas for a token for a given keyspace.
      * When the keyspace metadata has not been loaded, it returns null.
+     *
+     * Data of tables in tablet-based keyspaces (ScyllaDB tablets) is distributed per table,
+     * so for such keyspaces the name of the table is required. Replicas of a tablet are only known
+     * once the driver has learned about that tablet, from the responses to requests sent to the table.
+     * Until then, an empty list is returned.
      * @param {string} keyspaceName Name of the keyspace.
      * @param {Buffer | Token | TokenRange} token Can be Buffer (serialized partition key), Token or TokenRange.
-     * @returns {Array<Host>}.
+     * For a TokenRange, the replicas of its end token are returned.
+     * @param {string} [tableName] Name of the table. Required for tablet-based keyspaces.
+     * @returns {Array<Host> | null}
      */
-    getReplicas(keyspaceName, token) {
-        throw new Error("TODO: Not implemented");
+    getReplicas(keyspaceName, token, tableName) {
+        const rustClient = this.#client.rustClient;
+        if (!rustClient) {
+            return null;
+        }
+        if (token instanceof Buffer) {
+            return rustClient.getReplicasByRoutingKey(
+                keyspaceName,
+                tableName,
+                token,
+            );
+        }
+        if (token instanceof TokenRange) {
+            token = token.end;
+        }
+        return rustClient.getReplicasByToken(
+            keyspaceName,
+            tableName,
+            this.#tokenizer.toBigInt(token),
+        );
     }
 
     /**
@@ -86,18 +261,43 @@ class Metadata {
      * @returns {Set<TokenRange>} The ranges of the ring or empty set if schema metadata is not enabled.
      */
     getTokenRanges() {
-        throw new Error("TODO: Not implemented");
+        const rustClient = this.#client.rustClient;
+        if (!rustClient) {
+            return new Set();
+        }
+        return new Set(
+            rustClient
+                .getTokenRanges()
+                .map((range) => this.#tokenizer.rangeFromRust(range)),
+        );
     }
 
     /**
      * Gets the token ranges that are replicated on the given host, for the given keyspace.
+     *
+     * Tablet-based keyspaces (ScyllaDB tablets) distribute data per table rather than per token range
+     * of the ring, so this method throws for such keyspaces. Use {@link Metadata#getReplicas} with
+     * the table name instead.
      * @param {string} keyspaceName The name of the keyspace to get ranges for.
      * @param {Host} host The host.
      * @returns {Set<TokenRange> | null} Ranges for the keyspace on this host or null if keyspace
      * isn't found or hasn't been loaded.
      */
     getTokenRangesForHost(keyspaceName, host) {
-        throw new Error("TODO: Not implemented");
+        const rustClient = this.#client.rustClient;
+        if (!rustClient) {
+            return null;
+        }
+        const ranges = rustClient.getTokenRangesForHost(
+            keyspaceName,
+            host.hostId.getBuffer(),
+        );
+        if (!ranges) {
+            return null;
+        }
+        return new Set(
+            ranges.map((range) => this.#tokenizer.rangeFromRust(range)),
+        );
     }
 
     /**
@@ -117,7 +317,7 @@ class Metadata {
      * @returns {TokenRange} build range spanning from start (exclusive) to end (inclusive).
      */
     newTokenRange(start, end) {
-        throw new Error("TODO: Not implemented");
+        return new TokenRange(start, end, this.#tokenizer);
     }
 
     /**
@@ -127,7 +327,9 @@ class Metadata {
      * @returns {Udt | null} The UDT definition, or `null` if it does not exist.
      */
     getUdt(keyspaceName, name) {
-        throw new Error("TODO: Not implemented");
+        const keyspace = getOwn(this.#getRawKeyspaces(), keyspaceName);
+        const raw = keyspace && getOwn(keyspace.udts, name);
+        return raw ? this.#convertUdt(raw) : null;
     }
 
     /**
@@ -137,7 +339,9 @@ class Metadata {
      * @returns {TableMetadata | null} The table metadata, or `null` if it does not exist.
      */
     getTable(keyspaceName, name) {
-        throw new Error("TODO: Not implemented");
+        const keyspace = getOwn(this.#getRawKeyspaces(), keyspaceName);
+        const raw = keyspace && getOwn(keyspace.tables, name);
+        return raw ? this.#convertTable(raw) : null;
     }
 
     /**
@@ -184,16 +388,14 @@ class Metadata {
 
     /**
      * Gets the definition of a CQL materialized view for a given name.
-     *
-     * Note that, unlike the rest of the {@link Metadata} methods, this method does not cache the result for following
-     * calls, as the current version of the Cassandra native protocol does not support schema change events for
-     * materialized views. Each call to this method will produce one or more queries to the cluster.
      * @param {string} keyspaceName Name of the keyspace
      * @param {string} name Name of the materialized view
      * @returns {MaterializedView | null} The materialized view definition, or `null` if it does not exist.
      */
     getMaterializedView(keyspaceName, name) {
-        throw new Error("TODO: Not implemented");
+        const keyspace = getOwn(this.#getRawKeyspaces(), keyspaceName);
+        const raw = keyspace && getOwn(keyspace.views, name);
+        return raw ? this.#convertView(raw) : null;
     }
 
     /**
@@ -201,11 +403,53 @@ class Metadata {
      * query. The trace itself is stored in Cassandra in the `sessions` and
      * `events` table in the `system_traces` keyspace and can be
      * retrieve manually using the trace identifier.
+     *
+     * As the trace is written asynchronously by the nodes, it is read a few times until it is complete.
+     * When it is still incomplete after the last attempt, an error named `TracingError` is returned.
      * @param {Uuid} traceId Identifier of the trace session.
-     * @param {Number} [consistency] The consistency level to obtain the trace.
+     * @param {Number} [consistency] The consistency level to obtain the trace. Defaults to `ONE`.
+     * @param {Function} [callback] The callback function, invoked with the trace.
+     * When it is not provided, a Promise is returned.
+     * @returns {Promise<QueryTrace>|undefined}
      */
-    getTrace(traceId, consistency) {
-        throw new Error("TODO: Not implemented");
+    getTrace(traceId, consistency, callback) {
+        if (!callback && typeof consistency === "function") {
+            callback = consistency;
+            consistency = null;
+        }
+        return promiseUtils.optionalCallback(
+            this.#getTrace(traceId, consistency),
+            callback,
+        );
+    }
+
+    /**
+     * @param {Uuid} traceId
+     * @param {Number} [consistency]
+     * @returns {Promise<QueryTrace>}
+     */
+    async #getTrace(traceId, consistency) {
+        await this.#client.connect();
+        const trace = await this.#client.rustClient.getTrace(
+            traceId.getBuffer(),
+            consistency ?? undefined,
+        );
+        return {
+            requestType: trace.request,
+            coordinator: toInetAddress(trace.coordinator),
+            parameters: trace.parameters,
+            startedAt:
+                trace.startedAt != null ? new Date(trace.startedAt) : null,
+            duration: trace.duration,
+            clientAddress: toInetAddress(trace.client),
+            events: trace.events.map((event) => ({
+                id: TimeUuid.fromString(event.eventId),
+                activity: event.activity,
+                source: toInetAddress(event.source),
+                elapsed: event.sourceElapsed,
+                thread: event.thread,
+            })),
+        };
     }
 
     /**
@@ -213,11 +457,58 @@ class Metadata {
      *
      * This method performs a one-time check only, without any form of retry; therefore
      * `protocolOptions.maxSchemaAgreementWaitSeconds` setting does not apply in this case.
-     * @returns {Boolean} `true` when all hosts agree on the schema and `false` when there is no agreement or when
-     * the check could not be performed (for example, if the control connection is down).
+     * @param {Function} [callback] The callback function, invoked with the result of the check.
+     * When it is not provided, a Promise is returned.
+     * @returns {Promise<Boolean>|undefined} `true` when all hosts agree on the schema and `false` when there is
+     * no agreement or when the check could not be performed (for example, if the control connection is down).
      */
-    checkSchemaAgreement() {
-        throw new Error("TODO: Not implemented");
+    checkSchemaAgreement(callback) {
+        return promiseUtils.optionalCallback(
+            this.#checkSchemaAgreement(),
+            callback,
+        );
+    }
+
+    /**
+     * @returns {Promise<Boolean>}
+     */
+    async #checkSchemaAgreement() {
+        const rustClient = this.#client.rustClient;
+        if (!rustClient) {
+            return false;
+        }
+        try {
+            return (await rustClient.checkSchemaAgreement()) !== null;
+        } catch (e) {
+            return false;
+        }
+    }
+
+    /**
+     * Waits until the hosts that are currently up agree on the schema definition.
+     *
+     * The agreement is checked every `protocolOptions.schemaAgreementInterval` milliseconds,
+     * for at most `protocolOptions.maxSchemaAgreementWaitSeconds`. Use it after executing schema changes
+     * with the `waitForSchemaAgreement` query option disabled.
+     * @param {Function} [callback] The callback function, invoked with the agreed schema version.
+     * When it is not provided, a Promise is returned.
+     * @returns {Promise<Uuid>|undefined} The schema version the hosts agreed on. When the agreement is not reached
+     * in time, the promise is rejected with an error named `SchemaAgreementTimeoutError`.
+     */
+    awaitSchemaAgreement(callback) {
+        return promiseUtils.optionalCallback(
+            this.#awaitSchemaAgreement(),
+            callback,
+        );
+    }
+
+    /**
+     * @returns {Promise<Uuid>}
+     */
+    async #awaitSchemaAgreement() {
+        await this.#client.connect();
+        const version = await this.#client.rustClient.awaitSchemaAgreement();
+        return Uuid.fromString(version);
     }
 }
 
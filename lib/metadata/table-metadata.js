@@ -21,6 +21,8 @@ const ColumnKind = {
     ClusteringKey: 2,
     /** Column that is part of the partition key. */
     PartitionKey: 3,
+    /** Column of a kind that is not supported by the driver. */
+    Other: 4,
 };
 
 /**
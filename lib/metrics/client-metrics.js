@@ -1,6 +1,9 @@
 // @ts-nocheck
 "use strict";
 
+const errors = require("../errors");
+const { responseErrorCodes } = require("../types");
+
 /**
  * Represents a base class that is used to measure events from the server and the client as seen by the driver.
  * @alias module:metrics~ClientMetrics
@@ -107,6 +110,86 @@ class ClientMetrics {
      * Array, where nanoseconds is the remaining part of the real time that can't be represented in second precision.
      */
     onResponse(latency) {}
+
+    /**
+     * Creates the function, through which the driver passes the events it measured.
+     *
+     * The driver passes the events in batches, shortly after they occur.
+     * @param {Client} client
+     * @returns {function(Array<Object>): void}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration(client) {
+        return (events) => {
+            for (const event of events) {
+                // The metrics are called from the event loop, so their errors must not escape.
+                try {
+                    this.#measure(event);
+                } catch (err) {
+                    client.log(
+                        "warning",
+                        `Client metrics (${this.constructor.name}) failed: ${err.message}`,
+                    );
+                }
+            }
+        };
+    }
+
+    #measure(event) {
+        switch (event.kind) {
+            case "response":
+                this.onResponse(event.latency);
+                if (event.success) {
+                    this.onSuccessfulResponse(event.latency);
+                }
+                break;
+            case "error":
+                this.#onError(event.error);
+                break;
+            case "retry":
+                this.#onRetry(event.error);
+                break;
+            case "ignoredError":
+                this.onIgnoreError(event.error);
+                break;
+            case "speculativeExecution":
+                this.onSpeculativeExecution();
+                break;
+        }
+    }
+
+    #onError(e) {
+        if (e instanceof errors.OperationTimedOutError) {
+            return this.onClientTimeoutError(e);
+        }
+        if (e instanceof errors.AuthenticationError) {
+            return this.onAuthenticationError(e);
+        }
+        switch (e instanceof errors.ResponseError && e.code) {
+            case responseErrorCodes.readTimeout:
+                return this.onReadTimeoutError(e);
+            case responseErrorCodes.writeTimeout:
+                return this.onWriteTimeoutError(e);
+            case responseErrorCodes.unavailableException:
+                return this.onUnavailableError(e);
+            default:
+                return this.onOtherError(e);
+        }
+    }
+
+    #onRetry(e) {
+        switch (e instanceof errors.ResponseError && e.code) {
+            case responseErrorCodes.readTimeout:
+                return this.onReadTimeoutRetry(e);
+            case responseErrorCodes.writeTimeout:
+                return this.onWriteTimeoutRetry(e);
+            case responseErrorCodes.unavailableException:
+                return this.onUnavailableRetry(e);
+            default:
+                return this.onOtherErrorRetry(e);
+        }
+    }
 }
 
 module.exports = ClientMetrics;
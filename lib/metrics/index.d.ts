@@ -1,4 +1,4 @@
-import { errors } from "../../";
+import { Client, errors } from "../../";
 
 export interface ClientMetrics {
     onAuthenticationError(e: Error | errors.AuthenticationError): void;
@@ -32,6 +32,9 @@ export interface ClientMetrics {
     onWriteTimeoutError(e: errors.ResponseError): void;
 
     onWriteTimeoutRetry(e: Error): void;
+
+    /** @internal */
+    getRustConfiguration(client: Client): (events: Array<object>) => void;
   }
 
 export class DefaultMetrics implements ClientMetrics {
@@ -68,4 +71,47 @@ export class DefaultMetrics implements ClientMetrics {
     onWriteTimeoutError(e: errors.ResponseError): void;
 
     onWriteTimeoutRetry(e: Error): void;
+
+    /** @internal */
+    getRustConfiguration(client: Client): (events: Array<object>) => void;
+  }
+
+/** Latency statistics, in milliseconds. */
+export interface LatencyMetrics {
+    min: number;
+    max: number;
+    mean: number;
+    median: number;
+    percentile75: number;
+    percentile95: number;
+    percentile98: number;
+    percentile99: number;
+    percentile999: number;
+  }
+
+/** Metrics of the requests attempted on a single node. */
+export interface NodeMetrics {
+    hostId: string;
+    address: string;
+    requests: number;
+    errors: number;
+    timeouts: number;
+    retries: number;
+    latency?: LatencyMetrics;
+  }
+
+/** Metrics measured by the driver, with the metrics of each node keyed by its host id. */
+export interface DriverMetrics {
+    requests: number;
+    errors: number;
+    retries: number;
+    requestTimeouts: number;
+    connections: number;
+    connectionTimeouts: number;
+    meanRate: number;
+    oneMinuteRate: number;
+    fiveMinuteRate: number;
+    fifteenMinuteRate: number;
+    latency?: LatencyMetrics;
+    nodes: { [hostId: string]: NodeMetrics };
   }
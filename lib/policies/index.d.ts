@@ -42,6 +42,12 @@ export namespace loadBalancing {
       ): void;
 
       getOptions(): Map<string, object>;
+
+      /** @internal */
+      hasJsQueryPlan(): boolean;
+
+      /** @internal */
+      initJsQueryPlan(client: Client, hosts: HostMap, callback: EmptyCallback): void;
     }
 
     class DCAwareRoundRobinPolicy extends LoadBalancingPolicy {
@@ -109,12 +115,79 @@ export namespace reconnection {
   }
 
 export namespace retry {
+    class DowngradingConsistencyRetryPolicy extends RetryPolicy {
+      constructor(options?: { maxRetries?: number });
+    }
+
     class FallthroughRetryPolicy extends RetryPolicy {
       constructor();
     }
 
+    class IdempotenceAwareRetryPolicy extends RetryPolicy {
+      constructor(childPolicy?: RetryPolicy);
+    }
+
     class RetryPolicy {
-      constructor();
+      constructor(options?: { maxRetries?: number });
+
+      maxRetries?: number;
+
+      onReadTimeout(
+        info: OperationInfo,
+        consistency: types.consistencies,
+        received: number,
+        blockFor: number,
+        isDataPresent: boolean,
+      ): DecisionInfo;
+
+      onRequestError(
+        info: OperationInfo,
+        consistency: types.consistencies,
+        err: Error,
+      ): DecisionInfo;
+
+      onUnavailable(
+        info: OperationInfo,
+        consistency: types.consistencies,
+        required: number,
+        alive: number,
+      ): DecisionInfo;
+
+      onWriteTimeout(
+        info: OperationInfo,
+        consistency: types.consistencies,
+        received: number,
+        blockFor: number,
+        writeType: string,
+      ): DecisionInfo;
+
+      rethrowResult(): DecisionInfo;
+
+      retryResult(
+        consistency?: types.consistencies,
+        useCurrentHost?: boolean,
+      ): DecisionInfo;
+
+      ignoreResult(): DecisionInfo;
+    }
+
+    namespace RetryPolicy {
+      enum retryDecision {
+        ignore,
+        rethrow,
+        retry,
+      }
+    }
+
+    interface DecisionInfo {
+      decision: RetryPolicy.retryDecision;
+      consistency?: types.consistencies;
+      useCurrentHost?: boolean;
+    }
+
+    interface OperationInfo {
+      nbRetry: number;
+      isIdempotent: boolean;
     }
   }
 
@@ -136,6 +209,23 @@ export namespace speculativeExecution {
       shutdown(): void;
     }
 
+    class PercentileSpeculativeExecutionPolicy
+      implements SpeculativeExecutionPolicy
+    {
+      constructor(percentile: number, maxSpeculativeExecutions: number);
+
+      getOptions(): Map<string, object>;
+
+      init(client: Client): void;
+
+      newPlan(
+        keyspace: string,
+        queryInfo: string | Array<object>,
+      ): { nextExecution: Function };
+
+      shutdown(): void;
+    }
+
     class NoSpeculativeExecutionPolicy implements SpeculativeExecutionPolicy {
       constructor();
 
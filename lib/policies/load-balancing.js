@@ -5,6 +5,8 @@ const types = require("../types");
 const utils = require("../utils.js");
 const errors = require("../errors.js");
 const { throwNotSupported } = require("../new-utils.js");
+const { ExecutionOptions } = require("../execution-options.js");
+const token = require("../token.js");
 const _rust = require("../../index");
 
 const newlyUpInterval = 60000;
@@ -14,6 +16,12 @@ const newlyUpInterval = 60000;
  * Base class for Load Balancing Policies
  */
 class LoadBalancingPolicy {
+    /**
+     * Client used to compute the query plans in JavaScript, set once the policy is initialized.
+     * @type {Client?}
+     */
+    #queryPlanClient = null;
+
     constructor() {}
     /**
      * Initializes the load balancing policy, called after the driver obtained the information of the cluster.
@@ -62,11 +70,153 @@ class LoadBalancingPolicy {
      * @ignore
      */
     getRustConfiguration() {
+        if (this.hasJsQueryPlan()) {
+            return { queryPlan: (info) => this.#queryPlan(info) };
+        }
         // This error will be thrown by all policies, that do not override this method.
         throw new Error(
             "Currently this load balancing policy is not supported by the driver",
         );
     }
+
+    /**
+     * Determines whether the query plans of this policy are computed in JavaScript,
+     * which is the case for custom policies, that implement `newQueryPlan()`.
+     * @returns {boolean}
+     * @internal
+     * @ignore
+     */
+    hasJsQueryPlan() {
+        return (
+            this.newQueryPlan !== LoadBalancingPolicy.prototype.newQueryPlan
+        );
+    }
+
+    /**
+     * Initializes the policy, and starts computing the query plans of the requests of the client.
+     * Until then, the query plans are empty.
+     * @param {Client} client
+     * @param {HostMap} hosts
+     * @param {Function} callback
+     * @internal
+     * @ignore
+     */
+    initJsQueryPlan(client, hosts, callback) {
+        this.init(client, hosts, (err) => {
+            if (!err) {
+                this.#queryPlanClient = client;
+            }
+            callback(err);
+        });
+    }
+
+    /**
+     * Computes the query plan of a request, called by the Rust driver for each request.
+     *
+     * The Rust driver waits for the plan, so `newQueryPlan()` has to invoke the callback synchronously.
+     * Each host is tried at most once, so the plan is limited to the number of hosts in the cluster.
+     * @param {_rust.RoutingInfoObj} info
+     * @returns {Array<string>} Ids of the hosts to try, in order.
+     */
+    #queryPlan(info) {
+        const client = this.#queryPlanClient;
+        if (!client) {
+            return [];
+        }
+        try {
+            // Query plans are computed from the latest cached list of hosts.
+            this.hosts = client.hosts;
+            let result = null;
+            this.newQueryPlan(
+                client.keyspace,
+                new RoutingExecutionOptions(info),
+                (err, iterator) => (result = { err, iterator }),
+            );
+            if (!result) {
+                throw new Error(
+                    "The query plan has to be provided synchronously by newQueryPlan()",
+                );
+            }
+            if (result.err) {
+                throw result.err;
+            }
+            const hostIds = [];
+            const maxLength = this.hosts.length;
+            for (const host of { [Symbol.iterator]: () => result.iterator }) {
+                if (hostIds.length >= maxLength) {
+                    break;
+                }
+                hostIds.push(host.hostId.toString());
+            }
+            return hostIds;
+        } catch (err) {
+            client.log(
+                "warning",
+                `Load balancing policy (${this.constructor.name}) failed to provide the query plan: ${err.message}`,
+            );
+            return [];
+        }
+    }
+}
+
+/**
+ * Information about a request, provided to the query plans of custom load balancing policies.
+ * @ignore
+ */
+class RoutingExecutionOptions extends ExecutionOptions {
+    /**
+     * @type {_rust.RoutingInfoObj}
+     */
+    #info;
+
+    /**
+     * @param {_rust.RoutingInfoObj} info
+     */
+    constructor(info) {
+        super();
+        this.#info = info;
+    }
+
+    getConsistency() {
+        return this.#info.consistency;
+    }
+
+    getKeyspace() {
+        return this.#info.keyspace ?? undefined;
+    }
+
+    getSerialConsistency() {
+        return this.#info.serialConsistency ?? undefined;
+    }
+
+    /**
+     * Gets the token of the partition targeted by the request, when it is known.
+     * @returns {token.Murmur3Token|undefined}
+     */
+    getRoutingToken() {
+        if (this.#info.token === undefined || this.#info.token === null) {
+            return undefined;
+        }
+        return new token.Murmur3Token(
+            types.Long.fromString(this.#info.token.toString()),
+        );
+    }
+
+    /**
+     * Gets the name of the table targeted by the request, when it is known.
+     * @returns {string|undefined}
+     */
+    getTable() {
+        return this.#info.table ?? undefined;
+    }
+
+    /**
+     * Determines whether the request is a lightweight transaction.
+     * @returns {boolean}
+     */
+    isLwt() {
+        return this.#info.isLwt;
+    }
 }
 
 class LoadBalancingRustImplemented extends LoadBalancingPolicy {
@@ -86,6 +236,9 @@ class LoadBalancingRustImplemented extends LoadBalancingPolicy {
     newQueryPlan(keyspace, executionOptions, callback) {
         throwNotSupported(this.errorMsg);
     }
+    hasJsQueryPlan() {
+        return false;
+    }
 }
 
 /**
@@ -361,6 +514,17 @@ class LegacyDefaultLoadBalancingPolicy extends LoadBalancingPolicy {
         callback();
     }
 
+    /**
+     * This policy depends on the events of the hosts, that are not emitted by the driver,
+     * so its query plans cannot be computed in JavaScript.
+     * @returns {boolean}
+     * @internal
+     * @ignore
+     */
+    hasJsQueryPlan() {
+        return false;
+    }
+
     /**
      * Returns the distance assigned by this policy to the provided host, relatively to the client instance.
      * @param {Host} host
@@ -1,6 +1,8 @@
 // @ts-nocheck
 "use strict";
 const util = require("util");
+// eslint-disable-next-line no-unused-vars
+const rust = require("../../index");
 
 /** @module policies/reconnection */
 /**
@@ -26,6 +28,17 @@ ReconnectionPolicy.prototype.getOptions = function () {
     return new Map();
 };
 
+/**
+ * @returns {rust.ReconnectionPolicyConfig}
+ * @internal
+ * @ignore
+ */
+ReconnectionPolicy.prototype.getRustConfiguration = function () {
+    throw new TypeError(
+        "Currently only built-in reconnection policies are supported. Inheriting from ReconnectionPolicy is not supported.",
+    );
+};
+
 /**
  * A reconnection policy that waits a constant time between each reconnection attempt.
  * @param {Number} delay Delay in ms
@@ -57,6 +70,20 @@ ConstantReconnectionPolicy.prototype.getOptions = function () {
     return new Map([["delay", this.delay]]);
 };
 
+/**
+ * @returns {rust.ReconnectionPolicyConfig}
+ * @internal
+ * @ignore
+ */
+ConstantReconnectionPolicy.prototype.getRustConfiguration = function () {
+    if (this.constructor !== ConstantReconnectionPolicy) {
+        throw new TypeError(
+            "Currently only built-in reconnection policies are supported. Inheriting from ConstantReconnectionPolicy is not supported.",
+        );
+    }
+    return { delay: this.delay };
+};
+
 /**
  * A reconnection policy that waits exponentially longer between each
  * reconnection attempt (but keeps a constant delay once a maximum delay is reached).
@@ -140,6 +167,22 @@ ExponentialReconnectionPolicy.prototype.getOptions = function () {
     ]);
 };
 
+/**
+ * The driver always attempts to reconnect to a host immediately after the connections to it are lost,
+ * so `startWithNoDelay` is not used by the driver.
+ * @returns {rust.ReconnectionPolicyConfig}
+ * @internal
+ * @ignore
+ */
+ExponentialReconnectionPolicy.prototype.getRustConfiguration = function () {
+    if (this.constructor !== ExponentialReconnectionPolicy) {
+        throw new TypeError(
+            "Currently only built-in reconnection policies are supported. Inheriting from ExponentialReconnectionPolicy is not supported.",
+        );
+    }
+    return { baseDelay: this.baseDelay, maxDelay: this.maxDelay };
+};
+
 exports.ReconnectionPolicy = ReconnectionPolicy;
 exports.ConstantReconnectionPolicy = ConstantReconnectionPolicy;
 exports.ExponentialReconnectionPolicy = ExponentialReconnectionPolicy;
@@ -1,6 +1,8 @@
 "use strict";
 
 const rust = require("../../index");
+const errors = require("../errors");
+const { consistencies, responseErrorCodes } = require("../types");
 
 /** @module policies/retry */
 /**
@@ -11,25 +13,265 @@ const rust = require("../../index");
  * https://github.com/scylladb/scylla-rust-driver/blob/main/scylla/src/policies/retry/default.rs
  * For more information see the DefaultRetryPolicy here:
  * https://docs.datastax.com/en/developer/java-driver/4.11/manual/core/retries/index.html
+ *
+ * Custom retry policies can be created by inheriting from this class and overriding
+ * the methods deciding what to do for each kind of error. Methods that are not overridden
+ * follow the behavior of the default policy. Decisions of custom policies are made in JS,
+ * which is slower than the built-in policies.
  */
 class RetryPolicy {
-    constructor() {}
+    /**
+     * Creates a new instance of RetryPolicy.
+     * @param {Object} [options]
+     * @param {Number} [options.maxRetries] The maximum number of retries of a request.
+     * When not set, each kind of error is retried at most once, except for request errors.
+     */
+    constructor(options) {
+        /**
+         * The maximum number of retries of a request.
+         * @type {Number|undefined}
+         */
+        this.maxRetries = options ? options.maxRetries : undefined;
+    }
+
+    /**
+     * Returns whether the request can be retried once more.
+     * @param {OperationInfo} info
+     * @param {Number} defaultMaxRetries Limit used when maxRetries is not set.
+     * @returns {Boolean}
+     * @protected
+     */
+    canRetry(info, defaultMaxRetries) {
+        const maxRetries =
+            this.maxRetries !== undefined ? this.maxRetries : defaultMaxRetries;
+        return info.nbRetry < maxRetries;
+    }
+
+    /**
+     * Determines what to do when the driver gets a ReadTimeoutException response from a Cassandra node.
+     *
+     * By default, the read is retried once on the same host,
+     * if enough replicas responded but the data was not retrieved.
+     * @param {OperationInfo} info
+     * @param {Number} consistency The [consistency]{@link module:types~consistencies} level of the query that triggered
+     * the exception.
+     * @param {Number} received The number of nodes having answered the request.
+     * @param {Number} blockFor The number of replicas whose response is required to achieve the
+     * required [consistency]{@link module:types~consistencies}.
+     * @param {Boolean} isDataPresent Whether the actual data was amongst the received replica responses.
+     * @returns {DecisionInfo}
+     */
+    onReadTimeout(info, consistency, received, blockFor, isDataPresent) {
+        if (!this.canRetry(info, 1)) {
+            return this.rethrowResult();
+        }
+        return received >= blockFor && !isDataPresent
+            ? this.retryResult(undefined, true)
+            : this.rethrowResult();
+    }
+
+    /**
+     * Determines what to do when the driver gets an UnavailableException response from a Cassandra node.
+     *
+     * By default, the request is retried once on the next host.
+     * @param {OperationInfo} info
+     * @param {Number} consistency The [consistency]{@link module:types~consistencies} level of the query that triggered
+     * the exception.
+     * @param {Number} required The number of replicas whose response is required to achieve the
+     * required [consistency]{@link module:types~consistencies}.
+     * @param {Number} alive The number of replicas that were known to be alive when the request had been processed
+     * (since an unavailable exception has been triggered, there will be alive &lt; required)
+     * @returns {DecisionInfo}
+     */
+    onUnavailable(info, consistency, required, alive) {
+        if (!this.canRetry(info, 1)) {
+            return this.rethrowResult();
+        }
+        return this.retryResult(undefined, false);
+    }
+
+    /**
+     * Determines what to do when the driver gets a WriteTimeoutException response from a Cassandra node.
+     *
+     * By default, idempotent writes are retried once on the same host,
+     * if the timeout occurred while writing the distributed batch log.
+     * @param {OperationInfo} info
+     * @param {Number} consistency The [consistency]{@link module:types~consistencies} level of the query that triggered
+     * the exception.
+     * @param {Number} received The number of nodes having acknowledged the request.
+     * @param {Number} blockFor The number of replicas whose acknowledgement is required to achieve the required
+     * [consistency]{@link module:types~consistencies}.
+     * @param {String} writeType A <code>string</code> that describes the type of the write that timed out ("SIMPLE"
+     * / "BATCH" / "BATCH_LOG" / "UNLOGGED_BATCH" / "COUNTER").
+     * @returns {DecisionInfo}
+     */
+    onWriteTimeout(info, consistency, received, blockFor, writeType) {
+        if (!this.canRetry(info, 1) || !info.isIdempotent) {
+            return this.rethrowResult();
+        }
+        return writeType === "BATCH_LOG"
+            ? this.retryResult(undefined, true)
+            : this.rethrowResult();
+    }
+
+    /**
+     * Defines whether to retry and at which consistency level on an unexpected error.
+     *
+     * This method might be invoked in the following situations:
+     * 1. On a client timeout, while waiting for the server response.
+     * 2. On a connection error (socket closed, etc.).
+     * 3. When the contacted host replies with an error, such as `overloaded`, `isBootstrapping`,
+     * `serverError`, etc. In this case, the error is an instance of {@link ResponseError}.
+     *
+     * By default, idempotent requests are retried on the next host after server and connection errors,
+     * and all requests are retried on the next host if the contacted host is bootstrapping.
+     * @param {OperationInfo} info
+     * @param {Number|undefined} consistency The [consistency]{@link module:types~consistencies} level of the query that triggered
+     * the exception.
+     * @param {Error} err The error that caused this request to fail.
+     * @returns {DecisionInfo}
+     */
+    onRequestError(info, consistency, err) {
+        if (!this.canRetry(info, Infinity)) {
+            return this.rethrowResult();
+        }
+        if (err instanceof errors.ResponseError) {
+            switch (err.code) {
+                case responseErrorCodes.isBootstrapping:
+                    return this.retryResult(undefined, false);
+                case responseErrorCodes.overloaded:
+                case responseErrorCodes.serverError:
+                case responseErrorCodes.truncateError:
+                    break;
+                default:
+                    return this.rethrowResult();
+            }
+        } else if (!(err instanceof errors.NoHostAvailableError)) {
+            // Errors, that are not related to the state of the host, will not change when retried.
+            return this.rethrowResult();
+        }
+        return info.isIdempotent
+            ? this.retryResult(undefined, false)
+            : this.rethrowResult();
+    }
+
+    /**
+     * Returns a {@link DecisionInfo} to retry the request with the given [consistency]{@link module:types~consistencies}.
+     * @param {Number} [consistency] When specified, it retries the request with the given consistency.
+     * @param {Boolean} [useCurrentHost] When specified, determines if the retry should be made using the same coordinator.
+     * Default: true.
+     * @returns {DecisionInfo}
+     */
+    retryResult(consistency, useCurrentHost) {
+        return {
+            decision: RetryPolicy.retryDecision.retry,
+            consistency: consistency,
+            useCurrentHost: useCurrentHost !== false,
+        };
+    }
+
+    /**
+     * Returns a {@link DecisionInfo} to callback in error when a err is obtained for a given request.
+     * @returns {DecisionInfo}
+     */
+    rethrowResult() {
+        return { decision: RetryPolicy.retryDecision.rethrow };
+    }
+
+    /**
+     * Returns a {@link DecisionInfo} to ignore the error of a write request and return an empty result.
+     * @returns {DecisionInfo}
+     */
+    ignoreResult() {
+        return { decision: RetryPolicy.retryDecision.ignore };
+    }
+
+    /**
+     * Calls the method of the policy handling the given error.
+     * Called from Rust for each failed attempt of a request.
+     * @param {rust.RetryRequestObj} request
+     * @returns {DecisionInfo}
+     * @private
+     */
+    #decide(request) {
+        /** @type {OperationInfo} */
+        const info = {
+            nbRetry: request.nbRetry,
+            isIdempotent: request.isIdempotent,
+        };
+        const error = request.error;
+        try {
+            switch (error.kind) {
+                case "onReadTimeout":
+                    return this.onReadTimeout(
+                        info,
+                        error.consistency,
+                        error.received,
+                        error.blockFor,
+                        error.isDataPresent,
+                    );
+                case "onUnavailable":
+                    return this.onUnavailable(
+                        info,
+                        error.consistency,
+                        error.required,
+                        error.alive,
+                    );
+                case "onWriteTimeout":
+                    return this.onWriteTimeout(
+                        info,
+                        error.consistency,
+                        error.received,
+                        error.blockFor,
+                        error.writeType,
+                    );
+                default:
+                    return this.onRequestError(
+                        info,
+                        error.consistency,
+                        error.error,
+                    );
+            }
+        } catch (e) {
+            // Errors thrown by the policy are not propagated to the user,
+            // the error of the request is returned instead.
+            return this.rethrowResult();
+        }
+    }
 
     /**
-     * @returns {rust.RetryPolicyKind}
+     * @returns {rust.RetryPolicyConfig}
      * @internal
      * @ignore
      */
     getRustConfiguration() {
-        if (this.constructor !== RetryPolicy) {
-            throw new TypeError(
-                "Currently only built-in retry policies are supported. Inheriting from RetryPolicy is not supported.",
-            );
+        if (this.constructor === RetryPolicy) {
+            return {
+                kind: rust.RetryPolicyKind.Default,
+                maxRetries: this.maxRetries,
+            };
         }
-        return rust.RetryPolicyKind.Default;
+        return {
+            kind: rust.RetryPolicyKind.Custom,
+            decide: (request) => this.#decide(request),
+        };
     }
 }
 
+/**
+ * Determines the retry decision for the retry policies.
+ * @type {Object}
+ * @property {Number} rethrow
+ * @property {Number} retry
+ * @property {Number} ignore
+ * @static
+ */
+RetryPolicy.retryDecision = {
+    rethrow: 0,
+    retry: 1,
+    ignore: 2,
+};
+
 /**
  * A retry policy that never retries and returns errors straight to the user.
  *
@@ -50,19 +292,291 @@ class FallthroughRetryPolicy extends RetryPolicy {
     }
 
     /**
-     * @returns {rust.RetryPolicyKind}
+     * Implementation of RetryPolicy method that returns [rethrow]{@link module:policies/retry~RetryPolicy.rethrowResult()}.
+     */
+    onReadTimeout() {
+        return this.rethrowResult();
+    }
+
+    /**
+     * Implementation of RetryPolicy method that returns [rethrow]{@link module:policies/retry~RetryPolicy.rethrowResult()}.
+     */
+    onRequestError() {
+        return this.rethrowResult();
+    }
+
+    /**
+     * Implementation of RetryPolicy method that returns [rethrow]{@link module:policies/retry~RetryPolicy.rethrowResult()}.
+     */
+    onUnavailable() {
+        return this.rethrowResult();
+    }
+
+    /**
+     * Implementation of RetryPolicy method that returns [rethrow]{@link module:policies/retry~RetryPolicy.rethrowResult()}.
+     */
+    onWriteTimeout() {
+        return this.rethrowResult();
+    }
+
+    /**
+     * @returns {rust.RetryPolicyConfig}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        if (this.constructor === FallthroughRetryPolicy) {
+            return { kind: rust.RetryPolicyKind.Fallthrough };
+        }
+        return super.getRustConfiguration();
+    }
+}
+
+/**
+ * A retry policy that retries requests with a lower consistency level,
+ * when not enough replicas are available or responded.
+ *
+ * Using this policy breaks the consistency guarantees of the requests, as the retried request
+ * may succeed while being applied on less replicas than requested.
+ *
+ * This policy is implemented on the Rust side, see:
+ * https://github.com/scylladb/scylla-rust-driver/blob/main/scylla/src/policies/retry/downgrading_consistency.rs
+ *
+ * @alias module:policies/retry~DowngradingConsistencyRetryPolicy
+ * @extends RetryPolicy
+ */
+class DowngradingConsistencyRetryPolicy extends RetryPolicy {
+    /**
+     * Creates a new instance of DowngradingConsistencyRetryPolicy.
+     * @param {Object} [options]
+     * @param {Number} [options.maxRetries] The maximum number of retries of a request.
+     * When not set, a request is retried at most once, except for request errors.
+     */
+    constructor(options) {
+        super(options);
+    }
+
+    /**
+     * Returns the decision to retry with the highest consistency level,
+     * that is likely to be achieved by the given number of replicas.
+     * @param {Number} knownOk
+     * @param {Number} consistency
+     * @returns {DecisionInfo}
+     * @private
+     */
+    #maxLikelyToWorkConsistency(knownOk, consistency) {
+        if (knownOk >= 3) {
+            return this.retryResult(consistencies.three, true);
+        }
+        if (knownOk === 2) {
+            return this.retryResult(consistencies.two, true);
+        }
+        if (knownOk === 1 || consistency === consistencies.eachQuorum) {
+            return this.retryResult(consistencies.one, true);
+        }
+        return this.rethrowResult();
+    }
+
+    /**
+     * Retries with a lower consistency level if not enough replicas responded,
+     * or once with the same consistency level if enough replicas responded but the data was not retrieved.
+     * @override
+     */
+    onReadTimeout(info, consistency, received, blockFor, isDataPresent) {
+        if (isSerial(consistency) || !this.canRetry(info, 1)) {
+            return this.rethrowResult();
+        }
+        if (received < blockFor) {
+            return this.#maxLikelyToWorkConsistency(received, consistency);
+        }
+        return isDataPresent
+            ? this.rethrowResult()
+            : this.retryResult(undefined, true);
+    }
+
+    /**
+     * Retries with a lower consistency level, that can be achieved by the alive replicas.
+     * Serial requests are retried on the next host.
+     * @override
+     */
+    onUnavailable(info, consistency, required, alive) {
+        if (!this.canRetry(info, 1)) {
+            return this.rethrowResult();
+        }
+        if (isSerial(consistency)) {
+            return this.retryResult(undefined, false);
+        }
+        return this.#maxLikelyToWorkConsistency(alive, consistency);
+    }
+
+    /**
+     * For idempotent requests, ignores the timeout if the write was applied by at least one replica,
+     * and retries unlogged batches with a lower consistency level and batch log writes with the same one.
+     * @override
+     */
+    onWriteTimeout(info, consistency, received, blockFor, writeType) {
+        if (
+            isSerial(consistency) ||
+            !this.canRetry(info, 1) ||
+            !info.isIdempotent
+        ) {
+            return this.rethrowResult();
+        }
+        switch (writeType) {
+            case "SIMPLE":
+            case "BATCH":
+                return received > 0
+                    ? this.ignoreResult()
+                    : this.rethrowResult();
+            case "UNLOGGED_BATCH":
+                return this.#maxLikelyToWorkConsistency(received, consistency);
+            case "BATCH_LOG":
+                return this.retryResult(undefined, true);
+            default:
+                return this.rethrowResult();
+        }
+    }
+
+    /**
+     * @returns {rust.RetryPolicyConfig}
      * @internal
      * @ignore
      */
     getRustConfiguration() {
-        if (this.constructor !== FallthroughRetryPolicy) {
-            throw new TypeError(
-                "Currently only built-in retry policies are supported. Inheriting from FallthroughRetryPolicy is not supported.",
+        if (this.constructor === DowngradingConsistencyRetryPolicy) {
+            return {
+                kind: rust.RetryPolicyKind.DowngradingConsistency,
+                maxRetries: this.maxRetries,
+            };
+        }
+        return super.getRustConfiguration();
+    }
+}
+
+/**
+ * A retry policy that avoids retrying requests that are not idempotent.
+ *
+ * In case of write timeouts and request errors, the request may have been applied by the node,
+ * so it is retried only if it is idempotent. Other decisions are delegated to the child policy.
+ *
+ * This policy is implemented on the Rust side, when the child policy is a built-in policy
+ * or a custom policy that is not combined with other policies.
+ *
+ * @alias module:policies/retry~IdempotenceAwareRetryPolicy
+ * @extends RetryPolicy
+ */
+class IdempotenceAwareRetryPolicy extends RetryPolicy {
+    /**
+     * Creates a new instance of IdempotenceAwareRetryPolicy.
+     * @param {RetryPolicy} [childPolicy] The retry policy to wrap. When not defined, it will use an instance of
+     * [RetryPolicy]{@link module:policies/retry~RetryPolicy} as child policy.
+     */
+    constructor(childPolicy) {
+        super();
+        this._childPolicy = childPolicy || new RetryPolicy();
+    }
+
+    /** @override */
+    onReadTimeout(info, consistency, received, blockFor, isDataPresent) {
+        return this._childPolicy.onReadTimeout(
+            info,
+            consistency,
+            received,
+            blockFor,
+            isDataPresent,
+        );
+    }
+
+    /**
+     * If the request is idempotent, delegates the decision to the child policy,
+     * otherwise rethrows the error.
+     * @override
+     */
+    onRequestError(info, consistency, err) {
+        if (info.isIdempotent) {
+            return this._childPolicy.onRequestError(info, consistency, err);
+        }
+        return this.rethrowResult();
+    }
+
+    /** @override */
+    onUnavailable(info, consistency, required, alive) {
+        return this._childPolicy.onUnavailable(
+            info,
+            consistency,
+            required,
+            alive,
+        );
+    }
+
+    /**
+     * If the request is idempotent, delegates the decision to the child policy,
+     * otherwise rethrows the error.
+     * @override
+     */
+    onWriteTimeout(info, consistency, received, blockFor, writeType) {
+        if (info.isIdempotent) {
+            return this._childPolicy.onWriteTimeout(
+                info,
+                consistency,
+                received,
+                blockFor,
+                writeType,
             );
         }
-        return rust.RetryPolicyKind.Fallthrough;
+        return this.rethrowResult();
+    }
+
+    /**
+     * @returns {rust.RetryPolicyConfig}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        if (this.constructor !== IdempotenceAwareRetryPolicy) {
+            return super.getRustConfiguration();
+        }
+        const child = this._childPolicy.getRustConfiguration();
+        if (child.kind === rust.RetryPolicyKind.IdempotenceAware) {
+            // Wrapping the policy twice does not change its decisions.
+            return child;
+        }
+        return {
+            ...child,
+            kind: rust.RetryPolicyKind.IdempotenceAware,
+            childKind: child.kind,
+        };
     }
 }
 
+/**
+ * @param {Number} consistency
+ * @returns {Boolean}
+ */
+function isSerial(consistency) {
+    return (
+        consistency === consistencies.serial ||
+        consistency === consistencies.localSerial
+    );
+}
+
+/**
+ * Request, for which the retry policy makes a decision.
+ * @typedef {Object} OperationInfo
+ * @property {Number} nbRetry The number of retries already performed for this request.
+ * @property {Boolean} isIdempotent Whether the request is idempotent.
+ */
+
+/**
+ * Decision of the retry policy.
+ * @typedef {Object} DecisionInfo
+ * @property {Number} decision The decision as specified in
+ * [retryDecision]{@link module:policies/retry~RetryPolicy.retryDecision}.
+ * @property {Number} [consistency] The [consistency level]{@link module:types~consistencies}.
+ * @property {Boolean} [useCurrentHost] Determines if it should use the same host to retry the request.
+ */
+
+exports.DowngradingConsistencyRetryPolicy = DowngradingConsistencyRetryPolicy;
 exports.FallthroughRetryPolicy = FallthroughRetryPolicy;
+exports.IdempotenceAwareRetryPolicy = IdempotenceAwareRetryPolicy;
 exports.RetryPolicy = RetryPolicy;
@@ -2,6 +2,8 @@
 "use strict";
 
 const errors = require("../errors");
+// eslint-disable-next-line no-unused-vars
+const rust = require("../../index");
 
 /** @module policies/speculativeExecution */
 
@@ -45,6 +47,16 @@ class SpeculativeExecutionPolicy {
     getOptions() {
         return new Map();
     }
+    /**
+     * @returns {rust.SpeculativeExecutionConfig|undefined}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        throw new TypeError(
+            "Currently only built-in speculative execution policies are supported. Inheriting from SpeculativeExecutionPolicy is not supported.",
+        );
+    }
 }
 
 /**
@@ -68,6 +80,14 @@ class NoSpeculativeExecutionPolicy extends SpeculativeExecutionPolicy {
     newPlan() {
         return this.#plan;
     }
+    /**
+     * @returns {undefined}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        return undefined;
+    }
 }
 
 /**
@@ -121,8 +141,95 @@ class ConstantSpeculativeExecutionPolicy extends SpeculativeExecutionPolicy {
             ["maxSpeculativeExecutions", this.#maxSpeculativeExecutions],
         ]);
     }
+    /**
+     * @returns {rust.SpeculativeExecutionConfig}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        if (this.constructor !== ConstantSpeculativeExecutionPolicy) {
+            throw new TypeError(
+                "Currently only built-in speculative execution policies are supported. Inheriting from ConstantSpeculativeExecutionPolicy is not supported.",
+            );
+        }
+        return {
+            maxSpeculativeExecutions: this.#maxSpeculativeExecutions,
+            delay: this.#delay,
+        };
+    }
+}
+
+/**
+ * A {@link SpeculativeExecutionPolicy} that schedules a given number of speculative executions,
+ * each of them after the request to the current host takes longer than the given percentile of the latencies
+ * of all requests executed by the client.
+ *
+ * This policy is implemented on the Rust side.
+ * @extends {SpeculativeExecutionPolicy}
+ */
+class PercentileSpeculativeExecutionPolicy extends SpeculativeExecutionPolicy {
+    #percentile;
+    #maxSpeculativeExecutions;
+
+    /**
+     * Creates a new instance of PercentileSpeculativeExecutionPolicy.
+     * @param {Number} percentile The percentile of the request latencies to wait for before each speculative execution,
+     * for example `99`. Must be greater than 0 and lower than 100.
+     * @param {Number} maxSpeculativeExecutions The amount of speculative executions that should be scheduled after the
+     * initial execution. Must be strictly positive.
+     */
+    constructor(percentile, maxSpeculativeExecutions) {
+        super();
+        if (!(percentile > 0 && percentile < 100)) {
+            throw new errors.ArgumentError(
+                "percentile must be a number between 0 and 100 (exclusive)",
+            );
+        }
+        if (!(maxSpeculativeExecutions > 0)) {
+            throw new errors.ArgumentError(
+                "maxSpeculativeExecutions must be a positive number",
+            );
+        }
+        this.#percentile = percentile;
+        this.#maxSpeculativeExecutions = maxSpeculativeExecutions;
+    }
+    /**
+     * The delays of this policy are computed by the driver, so they are not known on the JS side.
+     */
+    newPlan() {
+        throw new Error(
+            "PercentileSpeculativeExecutionPolicy is implemented in Rust and does not provide plans",
+        );
+    }
+    /**
+     * Gets an associative array containing the policy options.
+     */
+    getOptions() {
+        return new Map([
+            ["percentile", this.#percentile],
+            ["maxSpeculativeExecutions", this.#maxSpeculativeExecutions],
+        ]);
+    }
+    /**
+     * @returns {rust.SpeculativeExecutionConfig}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        if (this.constructor !== PercentileSpeculativeExecutionPolicy) {
+            throw new TypeError(
+                "Currently only built-in speculative execution policies are supported. Inheriting from PercentileSpeculativeExecutionPolicy is not supported.",
+            );
+        }
+        return {
+            maxSpeculativeExecutions: this.#maxSpeculativeExecutions,
+            percentile: this.#percentile,
+        };
+    }
 }
 
 exports.NoSpeculativeExecutionPolicy = NoSpeculativeExecutionPolicy;
 exports.SpeculativeExecutionPolicy = SpeculativeExecutionPolicy;
 exports.ConstantSpeculativeExecutionPolicy = ConstantSpeculativeExecutionPolicy;
+exports.PercentileSpeculativeExecutionPolicy =
+    PercentileSpeculativeExecutionPolicy;
@@ -4,6 +4,8 @@
 const util = require("util");
 const { Long } = require("../types");
 const errors = require("../errors");
+// eslint-disable-next-line no-unused-vars
+const rust = require("../../index");
 
 /** @module policies/timestampGeneration */
 
@@ -49,6 +51,16 @@ class TimestampGenerator {
     next(client) {
         throw new Error("next() must be implemented");
     }
+    /**
+     * @returns {rust.TimestampGeneratorConfig}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        throw new TypeError(
+            "Currently only built-in timestamp generators are supported. Inheriting from TimestampGenerator is not supported.",
+        );
+    }
 }
 
 /**
@@ -144,6 +156,22 @@ class MonotonicTimestampGenerator extends TimestampGenerator {
         }
         return result;
     }
+    /**
+     * @returns {rust.TimestampGeneratorConfig}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration() {
+        if (this.constructor !== MonotonicTimestampGenerator) {
+            throw new TypeError(
+                "Currently only built-in timestamp generators are supported. Inheriting from MonotonicTimestampGenerator is not supported.",
+            );
+        }
+        return {
+            warningThreshold: this.#warningThreshold,
+            minLogInterval: Math.max(this.#minLogInterval, 0),
+        };
+    }
     /**
      * @private
      * @returns {Number|Long}
@@ -29,7 +29,8 @@ const rust = require("../index");
  * [TODO: Add support for this field]
  * @property {string|ExecutionProfile} [executionProfile] Name or instance of the [profile]{@link ExecutionProfile} to
  * be used for this execution. If not set, it will the use "default" execution profile.
- * [TODO: Add support for this field]
+ *
+ * The profile must be declared in the `profiles` client option.
  * @property {number} [fetchSize] Amount of rows to retrieve per page. Only valid if query is paged.
  * @property {Array<any>|Array<Array<any>>} [hints] Type hints for parameters given in the query, ordered as for the parameters.
  *
@@ -58,17 +59,20 @@ const rust = require("../index");
  * [TODO: Add support for this field]
  * @property {string} [keyspace] Specifies the keyspace for the query. It is used for the following:
  *
- * 1. To indicate what keyspace the statement is applicable to (protocol V5+ only).  This is useful when the
+ * 1. To indicate what keyspace the statement is applicable to. This is useful when the
  * query does not provide an explicit keyspace and you want to override the current {@link Client#keyspace}.
  * 2. For query routing when the query operates on a different keyspace than the current {@link Client#keyspace}.
  *
- * [TODO: Add support for this field]
+ * The protocol used by the driver cannot set the keyspace per request, so the driver qualifies the tables
+ * of the statement, that are not qualified yet, with the keyspace instead. This is done for the tables of
+ * `SELECT`, `INSERT`, `UPDATE`, `DELETE`, `BATCH` and `TRUNCATE` statements, and of `CREATE`, `ALTER`
+ * and `DROP TABLE` statements. Statements are prepared separately for each keyspace they are executed in.
  * @property {boolean} [logged] Determines if the batch should be written to the batchlog. Only valid for
  * [Client#batch()]{@link Client#batch}, it will be ignored by other methods. Default: true.
- * [TODO: Add support for this field]
  * @property {boolean} [counter] Determines if its a counter batch. Only valid for
  * [Client#batch()]{@link Client#batch}, it will be ignored by other methods. Default: false.
- * [TODO: Add support for this field]
+ *
+ * Counter batches are never written to the batchlog, so setting both `counter` and `logged` to `true` is an error.
  * @property {boolean} [paged] Determines if the query should be paged. Default: true.
  * @property {Buffer|string} [pageState] Buffer or string token representing the paging state.
  *
@@ -76,14 +80,14 @@ const rust = require("../index");
  * [TODO: Add support for this field]
  * @property {boolean} [prepare] Determines if the query must be executed as a prepared statement.
  * @property {number} [readTimeout] When defined, it overrides the default read timeout
- * (`socketOptions.readTimeout`) in milliseconds for this execution per coordinator.
+ * (`socketOptions.readTimeout`) in milliseconds for this execution.
  *
  * Suitable for statements for which the coordinator may allow a longer server-side timeout, for example aggregation
  * queries.
  *
  * A value of `0` disables client side read timeout for the execution. Default: `undefined`.
  *
- * [TODO: Add support for this field]
+ * As with `socketOptions.readTimeout`, this timeout applies to the whole execution, including retries.
  * @property {RetryPolicy} [retry] Retry policy for the query.
  *
  * This property can be used to specify a different [retry policy]{@link module:policies/retry} to the one specified
@@ -91,12 +95,21 @@ const rust = require("../index");
  * [TODO: Add support for this field]
  * @property {Array<any>} [routingIndexes] Index of the parameters that are part of the partition key to determine
  * the routing.
- * [TODO: Add support for this field]
+ *
+ * Only used for non-prepared requests: prepared statements are routed using the partition key metadata
+ * returned by the server. For batches, the indexes refer to the parameters of the first statement.
+ *
+ * The name of the table is not known for non-prepared requests, so requests to tables of tablet-based keyspaces
+ * (ScyllaDB tablets) are not routed to the replicas of their partition, even with this option or `routingKey`.
  * @property {Buffer|Array<any>} [routingKey] Partition key(s) to determine which coordinator should be used for the query.
- * [TODO: Add support for this field]
+ *
+ * When provided, it takes precedence over `routingIndexes`. The key is hashed with the Murmur3 partitioner.
+ * [TODO: Add support for Token and TokenRange routing keys]
  * @property {Array<any>} [routingNames] Array of the parameters names that are part of the partition key to determine the
- * routing. Only valid for non-prepared requests, it's recommended that you use the prepare flag instead.
- * [TODO: Add support for this field]
+ * routing.
+ *
+ * Not supported: unprepared statements cannot have named parameters, so unprepared requests and batches with this option
+ * are rejected with an `ArgumentError`. Use `routingIndexes` or `routingKey`, or the prepare flag instead.
  * @property {number} [serialConsistency] Serial consistency is the consistency level for the serial phase of
  * conditional updates.
  * This option will be ignored for anything else that a conditional update/insert.
@@ -112,8 +125,26 @@ const rust = require("../index");
  * problems related to query executions. Default: false.
  *
  * To retrieve trace, you can call [Metadata.getTrace()]{@link module:metadata~Metadata#getTrace} method.
+ * @property {boolean} [waitForSchemaAgreement] Determines if the driver should wait for schema agreement
+ * between the hosts after a schema change executed by the request. Default: true.
+ *
+ * When set to false, the request completes as soon as the coordinator applies the change. Use
+ * [Metadata.awaitSchemaAgreement()]{@link module:metadata~Metadata#awaitSchemaAgreement} to wait for the agreement
+ * later.
  */
 
+/**
+ * Returns the components of the routing key, as expected by the rust part.
+ * Tokens and token ranges are not supported as routing keys, and are ignored.
+ * @param {Buffer|Array<Buffer>|undefined} routingKey
+ * @returns {Array<Buffer>|undefined}
+ */
+function routingKeyParts(routingKey) {
+    if (Buffer.isBuffer(routingKey)) return [routingKey];
+    if (Array.isArray(routingKey)) return routingKey;
+    return undefined;
+}
+
 /**
  * Parses js query options into rust query options wrapper
  * @param {_execOptions.ExecutionOptions} options
@@ -127,14 +158,21 @@ function queryOptionsIntoWrapper(options) {
     rustOptions.autoPage = options.isAutoPage();
     rustOptions.captureStackTrace = options.getCaptureStackTrace();
     rustOptions.consistency = options.getConsistency();
-    rustOptions.counter = options.counter;
+    rustOptions.counter = options.isBatchCounter();
+    const profile = options.getProfile();
+    rustOptions.executionProfile = profile && profile.name;
     rustOptions.fetchSize = options.getFetchSize();
     rustOptions.isIdempotent = options.isIdempotent();
-    rustOptions.keyspace = options.keyspace;
-    rustOptions.logged = options.logged;
+    rustOptions.keyspace = options.getKeyspace();
+    // Counter batches are never logged, so for them only an explicitly set `logged` is passed,
+    // to reject the invalid combination instead of applying the default (true).
+    rustOptions.logged = options.isBatchCounter()
+        ? options.getRawQueryOptions().logged
+        : options.isBatchLogged();
     rustOptions.prepare = options.prepare;
     rustOptions.readTimeout = options.getReadTimeout();
     rustOptions.routingIndexes = options.getRoutingIndexes();
+    rustOptions.routingKey = routingKeyParts(options.getRoutingKey());
     rustOptions.routingNames = options.getRoutingNames();
     rustOptions.serialConsistency = options.getSerialConsistency();
     let timestamp = options.getTimestamp();
@@ -142,6 +180,8 @@ function queryOptionsIntoWrapper(options) {
     else if (timestamp) timestamp = BigInt(timestamp);
     rustOptions.timestamp = timestamp;
     rustOptions.traceQuery = options.isQueryTracing();
+    rustOptions.waitForSchemaAgreement =
+        options.getRawQueryOptions().waitForSchemaAgreement;
     let wrapper = new rust.QueryOptionsWrapper(rustOptions);
     return wrapper;
 }
@@ -0,0 +1,90 @@
+"use strict";
+
+const types = require("./types");
+const token = require("./token");
+
+// Murmur3 tokens are signed 64-bit integers.
+const minValue = -(2n ** 63n);
+const maxValue = 2n ** 63n - 1n;
+const ringSize = 2n ** 64n;
+
+/**
+ * Tokenizer for the token ring of the Murmur3 partitioner.
+ *
+ * The driver only supports partitioners with 64-bit integer tokens (Murmur3, and CDC in ScyllaDB),
+ * so all tokens produced by the driver are {@link token.Murmur3Token} instances.
+ * @ignore
+ */
+class Murmur3Tokenizer {
+    /**
+     * @returns {token.Murmur3Token} The minimum token of the ring.
+     */
+    minToken() {
+        return this.fromBigInt(minValue);
+    }
+
+    /**
+     * @param {bigint} value
+     * @returns {token.Murmur3Token}
+     */
+    fromBigInt(value) {
+        return new token.Murmur3Token(types.Long.fromString(value.toString()));
+    }
+
+    /**
+     * @param {token.Token} value
+     * @returns {bigint}
+     */
+    toBigInt(value) {
+        return BigInt(value.getValue().toString());
+    }
+
+    /**
+     * Parses the token representation reported by the server.
+     * @param {string} value
+     * @returns {token.Murmur3Token}
+     */
+    parse(value) {
+        return new token.Murmur3Token(types.Long.fromString(value));
+    }
+
+    /**
+     * Returns `numberOfSplits - 1` tokens, splitting the range `]start, end]` evenly.
+     * @param {token.Token} start
+     * @param {token.Token} end
+     * @param {number} numberOfSplits
+     * @returns {Array<token.Murmur3Token>}
+     */
+    split(start, end, numberOfSplits) {
+        const startValue = this.toBigInt(start);
+        let range = this.toBigInt(end) - startValue;
+        if (range <= 0n) {
+            // Range wraps around the ring, or covers the whole ring.
+            range += ringSize;
+        }
+        const splits = BigInt(numberOfSplits);
+        const result = [];
+        for (let i = 1n; i < splits; i++) {
+            let value = startValue + (range * i) / splits;
+            if (value > maxValue) {
+                value -= ringSize;
+            }
+            result.push(this.fromBigInt(value));
+        }
+        return result;
+    }
+
+    /**
+     * @param {import("../index").TokenRangeObj} range
+     * @returns {token.TokenRange}
+     */
+    rangeFromRust(range) {
+        return new token.TokenRange(
+            this.fromBigInt(range.start),
+            this.fromBigInt(range.end),
+            this,
+        );
+    }
+}
+
+module.exports = { Murmur3Tokenizer };
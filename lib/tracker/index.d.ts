@@ -1,4 +1,4 @@
-import { ExecutionOptions, Host } from "../../";
+import { Client, ExecutionOptions, Host } from "../../";
 
 export interface RequestTracker {
     onError(
@@ -22,6 +22,9 @@ export interface RequestTracker {
     ): void;
 
     shutdown(): void;
+
+    /** @internal */
+    getRustConfiguration(client: Client): (reports: Array<object>) => void;
   }
 
 export class RequestLogger implements RequestTracker {
@@ -1,6 +1,8 @@
 // @ts-nocheck
 "use strict";
 
+const { ExecutionOptions } = require("../execution-options");
+
 /**
  * Tracks request execution for a {@link Client}.
  *
@@ -62,6 +64,120 @@ class RequestTracker {
      * Invoked when the Client is being shutdown.
      */
     shutdown() {}
+
+    /**
+     * Creates the function, through which the driver reports the finished requests of the client.
+     *
+     * The driver reports the requests in batches, shortly after they finish.
+     * Only the number of the parameters is reported, so the parameters are always `null`.
+     * @param {Client} client
+     * @returns {function(Array<Object>): void}
+     * @internal
+     * @ignore
+     */
+    getRustConfiguration(client) {
+        return (reports) => {
+            for (const report of reports) {
+                // The tracker is called from the event loop, so its errors must not escape.
+                try {
+                    this.#report(client, report);
+                } catch (err) {
+                    client.log(
+                        "warning",
+                        `Request tracker (${this.constructor.name}) failed: ${err.message}`,
+                    );
+                }
+            }
+        };
+    }
+
+    #report(client, report) {
+        const host =
+            (report.coordinator && client.hosts.get(report.coordinator)) ||
+            unknownHost(report.coordinator);
+        const query = report.isBatch
+            ? report.queries.map((query) => ({ query }))
+            : report.queries[0];
+        const executionOptions = new ReportedExecutionOptions(report);
+        if (report.error) {
+            this.onError(
+                host,
+                query,
+                null,
+                executionOptions,
+                report.requestLength,
+                report.error,
+                report.latency,
+            );
+        } else {
+            this.onSuccess(
+                host,
+                query,
+                null,
+                executionOptions,
+                report.requestLength,
+                report.responseLength,
+                report.latency,
+            );
+        }
+    }
+}
+
+/**
+ * Information about the execution of a reported request.
+ * @ignore
+ */
+class ReportedExecutionOptions extends ExecutionOptions {
+    #report;
+
+    constructor(report) {
+        super();
+        this.#report = report;
+    }
+
+    isPrepared() {
+        return this.#report.isPrepared;
+    }
+
+    isBatchLogged() {
+        return this.#report.isBatchLogged;
+    }
+
+    /**
+     * Gets the number of the parameters of the request.
+     * @returns {Number}
+     */
+    getParametersCount() {
+        return this.#report.paramsCount;
+    }
+
+    /**
+     * Gets the number of the attempts made to execute the request.
+     * @returns {Number}
+     */
+    getAttempts() {
+        return this.#report.attempts;
+    }
+
+    /**
+     * Gets the number of the retries of the request.
+     * @returns {Number}
+     */
+    getRetries() {
+        return this.#report.retries;
+    }
+}
+
+/**
+ * Represents the coordinator of a request, when it is not one of the known hosts,
+ * or when the request failed before being sent to any host.
+ * @ignore
+ */
+function unknownHost(address) {
+    return {
+        address: address ?? null,
+        addressToString: () => address ?? "unknown host",
+    };
 }
 
 module.exports = RequestTracker;
@@ -149,7 +149,11 @@ export class Client extends events.EventEmitter {
 
   shutdown(callback: EmptyCallback): void;
 
-  getReplicas(keyspace: string, token: Buffer): Host[];
+  getReplicas(
+    keyspace: string,
+    token: Buffer,
+    tableName?: string,
+  ): Host[] | null;
 
   getState(): metadata.ClientState;
 }
@@ -168,10 +172,11 @@ export interface HostMap extends events.EventEmitter {
 
 export interface Host extends events.EventEmitter {
   address: net.SocketAddress;
-  cassandraVersion: string | null;
+  readonly cassandraVersion: string | null;
   datacenter: string | null;
   rack: string | null;
   tokens: string[];
+  readonly shardCount: number | null;
   hostId: types.Uuid;
 
   canBeConsideredAsUp(): boolean;
@@ -257,6 +262,7 @@ export interface ClientOptions {
   };
   pooling?: {
     coreConnectionsPerHost?: { [key: number]: number };
+    coreConnectionsPerShard?: number;
     heartBeatInterval?: number;
     maxRequestsPerConnection?: number;
     warmup?: boolean;
@@ -267,6 +273,7 @@ export interface ClientOptions {
     maxSchemaAgreementWaitSeconds?: number;
     maxVersion?: number;
     port?: number;
+    schemaAgreementInterval?: number;
   };
   promiseFactory?: (
     handler: (callback: (err: Error, result?: any) => void) => void,
@@ -328,6 +335,7 @@ export interface QueryOptions {
   serialConsistency?: number;
   timestamp?: number | Long;
   traceQuery?: boolean;
+  waitForSchemaAgreement?: boolean;
 }
 
 export class ExecutionProfile {
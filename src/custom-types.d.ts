@@ -48,6 +48,175 @@ export type EncodedValuesWrapper = Uint8Array | null | undefined
 // These are plain JS objects returned from Rust to JS
 // ---------------------------------------------------------------------------
 
+/** Column of a table, as returned by SessionWrapper.getKeyspaces. */
+export interface ColumnMetadataObj {
+  type: ComplexType
+  /** Value of the ColumnKind enum from lib/metadata/table-metadata.js */
+  kind: number
+}
+
+/** Table (or materialized view) schema, as returned by SessionWrapper.getKeyspaces. */
+export interface TableMetadataObj {
+  columns: Record<string, ColumnMetadataObj>
+  partitionKey: Array<string>
+  clusteringKey: Array<string>
+  partitioner: string | null
+}
+
+export interface MaterializedViewObj {
+  viewMetadata: TableMetadataObj
+  tableName: string
+}
+
+export interface UdtObj {
+  name: string
+  keyspace: string
+  fields: Array<{ name: string; type: ComplexType }>
+}
+
+/** Replication strategy. Values of kind match the StrategyKind enum from lib/metadata/keyspace-metadata.js */
+export type StrategyObj =
+  | { kind: 0; replicationFactor: number }
+  | { kind: 1; datacenterRepfactors: Record<string, number> }
+  | { kind: 2 }
+  | { kind: 3; name: string; data: Record<string, string> }
+
+/** Keyspace schema, as returned by SessionWrapper.getKeyspaces. */
+export interface KeyspaceMetadataObj {
+  strategy: StrategyObj
+  durableWrites: boolean
+  tables: Record<string, TableMetadataObj>
+  views: Record<string, MaterializedViewObj>
+  udts: Record<string, UdtObj>
+}
+
+/** Range of the token ring, start-exclusive and end-inclusive. */
+export interface TokenRangeObj {
+  start: bigint
+  end: bigint
+}
+
+/** Event of a request trace. Ids and addresses are strings. */
+export interface TraceEventObj {
+  eventId: string
+  activity?: string
+  source?: string
+  sourceElapsed?: number
+  thread?: string
+}
+
+/** Request trace, as returned by SessionWrapper.getTrace. startedAt is in milliseconds since the unix epoch. */
+export interface TraceObj {
+  client?: string
+  command?: string
+  coordinator?: string
+  duration?: number
+  parameters: Record<string, string>
+  request?: string
+  startedAt?: number
+  events: TraceEventObj[]
+}
+
+/** Error of a request attempt, passed to the custom retry policy. Values of kind match the methods of the JS RetryPolicy. */
+export type RetryErrorObj =
+  | { kind: "onReadTimeout"; consistency: number; received: number; blockFor: number; isDataPresent: boolean }
+  | { kind: "onUnavailable"; consistency: number; required: number; alive: number }
+  | { kind: "onWriteTimeout"; consistency: number; received: number; blockFor: number; writeType: string }
+  | { kind: "onRequestError"; consistency: number; error: Error }
+
+/** Failed request attempt, for which the custom retry policy makes a decision. */
+export interface RetryRequestObj {
+  nbRetry: number
+  isIdempotent: boolean
+  error: RetryErrorObj
+}
+
+/** Step of the SASL authentication of a connection, passed to the custom auth provider. Values of kind match the methods of the JS Authenticator. */
+export type AuthRequestObj =
+  | { kind: "initialResponse"; authenticatorId: number; authenticatorName: string }
+  | { kind: "evaluateChallenge"; authenticatorId: number; token: Buffer | null }
+  | { kind: "onAuthenticationSuccess"; authenticatorId: number; token: Buffer | null }
+  | { kind: "release"; authenticatorId: number }
+
+/** Finished request, reported to the request tracker. Latency is given as [seconds, nanoseconds]. */
+export interface RequestReportObj {
+  queries: Array<string>
+  isBatch: boolean
+  isBatchLogged: boolean
+  isPrepared: boolean
+  paramsCount: number
+  requestLength: number
+  responseLength?: number
+  coordinator?: string
+  attempts: number
+  retries: number
+  latency: Array<number>
+  error?: Error
+}
+
+/** Event measured by the driver, passed to the ClientMetrics. Latency is given as [seconds, nanoseconds]. */
+export type MetricsEventObj =
+  | { kind: "response"; latency: Array<number>; success: boolean }
+  | { kind: "error"; error: Error }
+  | { kind: "retry"; error: Error }
+  | { kind: "ignoredError"; error: Error }
+  | { kind: "speculativeExecution" }
+
+/** Change of the topology, or of the schema of the cluster, passed to the client. */
+export type ClusterEventObj =
+  | { kind: "hostAdd" | "hostRemove" | "hostUp" | "hostDown"; hostId: string }
+  | { kind: "schemaChange"; change: string; target: string; keyspace: string; name: string | null }
+
+/** Latency statistics, in milliseconds. */
+export interface LatencyObj {
+  min: number
+  max: number
+  mean: number
+  median: number
+  percentile75: number
+  percentile95: number
+  percentile98: number
+  percentile99: number
+  percentile999: number
+}
+
+/** Metrics of the requests attempted on a single node. */
+export interface NodeMetricsObj {
+  hostId: string
+  address: string
+  requests: number
+  errors: number
+  timeouts: number
+  retries: number
+  latency?: LatencyObj
+}
+
+/** Metrics of the Rust driver, with the metrics of each node of the cluster. */
+export interface MetricsObj {
+  requests: number
+  errors: number
+  retries: number
+  requestTimeouts: number
+  connections: number
+  connectionTimeouts: number
+  meanRate: number
+  oneMinuteRate: number
+  fiveMinuteRate: number
+  fifteenMinuteRate: number
+  latency?: LatencyObj
+  nodes: Array<NodeMetricsObj>
+}
+
+/** Routing information of a request, passed to the load balancing policies implemented in JS. */
+export interface RoutingInfoObj {
+  token?: bigint
+  keyspace?: string
+  table?: string
+  consistency: number
+  serialConsistency?: number
+  isLwt: boolean
+}
+
 // ---------------------------------------------------------------------------
 // Types for objects produced by define_js_to_rust_convertible_object.
 // These are plain JS objects passed from JS into Rust (all fields optional).
@@ -78,6 +247,56 @@ export interface LoadBalancingConfig {
   permitDcFailover?: boolean
   enableShufflingReplicas?: boolean
   allowList?: Array<string>
+  /** Computes the ids of the hosts to try for a request, for load balancing policies implemented in JS. */
+  queryPlan?: (info: RoutingInfoObj) => Array<string>
+}
+
+/** Decision of the custom retry policy. Values of decision match RetryPolicy.retryDecision from lib/policies/retry.js */
+export interface RetryDecisionObj {
+  decision?: number
+  consistency?: number
+  useCurrentHost?: boolean
+}
+
+/**
+ * Retry policy passed to SessionOptions.retryPolicy. decide is required for the custom policy.
+ * For the idempotence aware policy, the other fields configure the policy of childKind.
+ */
+export interface RetryPolicyConfig {
+  kind?: RetryPolicyKind
+  decide?: (request: RetryRequestObj) => RetryDecisionObj
+  maxRetries?: number
+  childKind?: RetryPolicyKind
+}
+
+/** Execution profile passed to SessionOptions.executionProfiles. */
+export interface ExecutionProfileConfig {
+  name?: string
+  consistency?: number
+  serialConsistency?: number
+  requestTimeout?: number
+  loadBalancingConfig?: LoadBalancingConfig
+  retryPolicy?: RetryPolicyConfig
+}
+
+/** Speculative execution policy passed to SessionOptions.speculativeExecution. */
+export interface SpeculativeExecutionConfig {
+  maxSpeculativeExecutions?: number
+  delay?: number
+  percentile?: number
+}
+
+/** Reconnection policy passed to SessionOptions.reconnectionPolicy. */
+export interface ReconnectionPolicyConfig {
+  delay?: number
+  baseDelay?: number
+  maxDelay?: number
+}
+
+/** Timestamp generator passed to SessionOptions.timestampGenerator. */
+export interface TimestampGeneratorConfig {
+  warningThreshold?: number
+  minLogInterval?: number
 }
 
 /** Options for creating a new session via SessionWrapper.createSession. */
@@ -89,10 +308,32 @@ export interface SessionOptions {
   clientId?: string
   credentialsUsername?: string
   credentialsPassword?: string
+  /** Authenticates the connections, for auth providers implemented in JS. */
+  authenticate?: (request: AuthRequestObj) => Promise<Buffer | null>
+  /** Receives the reports of the finished requests, for the request tracker. */
+  requestTracker?: (reports: Array<RequestReportObj>) => void
+  /** Receives the events measured by the driver, for the client metrics. */
+  metricsEvents?: (events: Array<MetricsEventObj>) => void
+  /** Receives the changes of the topology and of the schema of the cluster. */
+  clusterEvents?: (events: Array<ClusterEventObj>) => void
   cacheSize?: number
   sslOptions?: SslOptions
   loadBalancingConfig?: LoadBalancingConfig
-  retryPolicy?: RetryPolicyKind
+  retryPolicy?: RetryPolicyConfig
+  requestTimeout?: number
+  executionProfiles?: Array<ExecutionProfileConfig>
+  speculativeExecution?: SpeculativeExecutionConfig
+  timestampGenerator?: TimestampGeneratorConfig
+  reconnectionPolicy?: ReconnectionPolicyConfig
+  connectionsPerHost?: number
+  connectionsPerShard?: number
+  keepaliveInterval?: number
+  connectTimeout?: number
+  tcpNodelay?: boolean
+  tcpKeepaliveInterval?: number
+  defaultPort?: number
+  schemaAgreementTimeout?: number
+  schemaAgreementInterval?: number
 }
 
 /** Per-query options passed to QueryOptionsWrapper. */
@@ -101,6 +342,7 @@ export interface QueryOptionsObj {
   captureStackTrace?: boolean
   consistency?: number
   counter?: boolean
+  executionProfile?: string
   fetchSize?: number
   isIdempotent?: boolean
   keyspace?: string
@@ -108,10 +350,12 @@ export interface QueryOptionsObj {
   prepare?: boolean
   readTimeout?: number
   routingIndexes?: Array<number>
+  routingKey?: Array<Uint8Array | null | undefined>
   routingNames?: Array<string>
   serialConsistency?: number
   timestamp?: bigint
   traceQuery?: boolean
+  waitForSchemaAgreement?: boolean
 }
 
 
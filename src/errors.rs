@@ -1,9 +1,19 @@
-use std::{error::Error, fmt::Display};
+use std::{collections::HashMap, error::Error, fmt::Display, net::SocketAddr, time::Duration};
 
 use napi::{
     Env, JsValue, Status, Unknown,
-    bindgen_prelude::{JsObjectValue, ToNapiValue},
+    bindgen_prelude::{Buffer, FnArgs, JsObjectValue, Object, ToNapiValue},
 };
+use scylla::errors::{
+    DbError, ExecutionError, PrepareError, RequestAttemptError, SchemaAgreementError,
+    UseKeyspaceError,
+};
+
+use crate::utils::js_ctor::{
+    build_argument_error, build_no_host_available_error, build_operation_timed_out_error,
+    build_response_error,
+};
+use crate::utils::to_napi_obj::NamedMap;
 
 /// Custom result class, that supports extended errors.
 /// Returning Error variants will result in throwing errors in JS.
@@ -99,9 +109,28 @@ where
 /// You still need to ensure the value will be "thrown" (or used to reject promise if in async fn)
 /// This is done in the ToNapiValue of JsResult class
 /// We convert all error to this struct, to allow for proper type checking in handling of this errors
+#[derive(Clone)]
 pub struct ConvertedError {
     msg: String,
     name: &'static str,
+    /// Class of the JS driver errors, that this error is converted into.
+    /// Other errors are converted into plain JS errors, with the name of the Rust error type.
+    class: Option<DriverErrorClass>,
+}
+
+/// Error classes of the JS driver (from `lib/errors`), that errors of the Rust driver are classified into,
+/// so that JS code can tell them apart with `instanceof`.
+#[derive(Clone)]
+enum DriverErrorClass {
+    /// `ResponseError`: error returned by the database. Its details are exposed as properties of the JS error.
+    Response(DbError),
+    /// `NoHostAvailableError`: no node was able to execute the request.
+    /// Contains the error of the last attempt on each node tried, by node address.
+    NoHostAvailable(Vec<(SocketAddr, ConvertedError)>),
+    /// `OperationTimedOutError`: no response was received within the client-side timeout.
+    OperationTimedOut,
+    /// `ArgumentError`: the request was made with invalid options, and was not sent.
+    Argument,
 }
 
 impl<T> From<T> for ConvertedError
@@ -122,10 +151,12 @@ where
             //
             // For this reason, we also only expose the error class name,
             // and not the name of the specific kind, or the inner values of those errors.
+            // The only exception are the errors classified into classes of the JS driver (see `DriverErrorClass`).
             name: std::any::type_name::<T>()
                 .rsplit(":")
                 .next()
                 .expect("Text after splitting should contain at least one element"),
+            class: None,
         }
     }
 }
@@ -139,14 +170,321 @@ impl ToNapiValue for ConvertedError {
         val: Self,
     ) -> napi::Result<napi::sys::napi_value> {
         let env = Env::from_raw(env);
-        let mut e = env.create_error(make_js_error(val.msg))?;
-
-        e.set_named_property("name", val.name)?;
+        let e = match val.class {
+            Some(class) => class.build(&env, &val.msg)?,
+            None => {
+                let mut e = env.create_error(make_js_error(val.msg))?;
+                e.set_named_property("name", val.name)?;
+                e
+            }
+        };
 
         Ok(e.raw())
     }
 }
 
+impl DriverErrorClass {
+    /// Constructs the JS driver error, with the given message.
+    fn build<'env>(self, env: &'env Env, msg: &str) -> napi::Result<Object<'env>> {
+        match self {
+            DriverErrorClass::Response(db_error) => {
+                let mut e =
+                    build_response_error(env, FnArgs::from((response_error_code(&db_error), msg)))?
+                        .into_object();
+                set_db_error_properties(&mut e, db_error)?;
+                Ok(e)
+            }
+            DriverErrorClass::NoHostAvailable(inner_errors) => {
+                let inner_errors: HashMap<String, ConvertedError> = inner_errors
+                    .into_iter()
+                    .map(|(node, error)| (node.to_string(), error))
+                    .collect();
+                Ok(build_no_host_available_error(
+                    env,
+                    FnArgs::from((NamedMap::new(inner_errors), msg)),
+                )?
+                .into_object())
+            }
+            DriverErrorClass::OperationTimedOut => {
+                Ok(build_operation_timed_out_error(env, FnArgs::from((msg,)))?.into_object())
+            }
+            DriverErrorClass::Argument => {
+                Ok(build_argument_error(env, FnArgs::from((msg,)))?.into_object())
+            }
+        }
+    }
+}
+
+/// Returns the code of the error, as defined in the CQL protocol specification.
+///
+/// The code of the rate limit error is negotiated with each connection,
+/// and not known here, so `None` is returned for it.
+fn response_error_code(db_error: &DbError) -> Option<i32> {
+    Some(match db_error {
+        DbError::ServerError => 0x0000,
+        DbError::ProtocolError => 0x000A,
+        DbError::AuthenticationError => 0x0100,
+        DbError::Unavailable { .. } => 0x1000,
+        DbError::Overloaded => 0x1001,
+        DbError::IsBootstrapping => 0x1002,
+        DbError::TruncateError => 0x1003,
+        DbError::WriteTimeout { .. } => 0x1100,
+        DbError::ReadTimeout { .. } => 0x1200,
+        DbError::ReadFailure { .. } => 0x1300,
+        DbError::FunctionFailure { .. } => 0x1400,
+        DbError::WriteFailure { .. } => 0x1500,
+        DbError::SyntaxError => 0x2000,
+        DbError::Unauthorized => 0x2100,
+        DbError::Invalid => 0x2200,
+        DbError::ConfigError => 0x2300,
+        DbError::AlreadyExists { .. } => 0x2400,
+        DbError::Unprepared { .. } => 0x2500,
+        DbError::Other(code) => *code,
+        _ => return None,
+    })
+}
+
+/// Sets the details of the database error as properties of the JS error,
+/// with the same names as in the `ResponseError` of the DataStax driver.
+/// Values of `code` match `types.responseErrorCodes`.
+fn set_db_error_properties(error: &mut Object, db_error: DbError) -> napi::Result<()> {
+    if let Some(code) = response_error_code(&db_error) {
+        error.set_named_property("code", code)?;
+    }
+    match db_error {
+        DbError::Unavailable {
+            consistency,
+            required,
+            alive,
+        } => {
+            error.set_named_property("consistencies", consistency as u16)?;
+            error.set_named_property("required", required)?;
+            error.set_named_property("alive", alive)?;
+        }
+        DbError::ReadTimeout {
+            consistency,
+            received,
+            required,
+            data_present,
+        } => {
+            error.set_named_property("consistencies", consistency as u16)?;
+            error.set_named_property("received", received)?;
+            error.set_named_property("blockFor", required)?;
+            error.set_named_property("isDataPresent", data_present)?;
+        }
+        DbError::WriteTimeout {
+            consistency,
+            received,
+            required,
+            write_type,
+        } => {
+            error.set_named_property("consistencies", consistency as u16)?;
+            error.set_named_property("received", received)?;
+            error.set_named_property("blockFor", required)?;
+            error.set_named_property("writeType", write_type.as_str())?;
+        }
+        DbError::ReadFailure {
+            consistency,
+            received,
+            required,
+            numfailures,
+            data_present,
+        } => {
+            error.set_named_property("consistencies", consistency as u16)?;
+            error.set_named_property("received", received)?;
+            error.set_named_property("blockFor", required)?;
+            error.set_named_property("failures", numfailures)?;
+            error.set_named_property("isDataPresent", data_present)?;
+        }
+        DbError::WriteFailure {
+            consistency,
+            received,
+            required,
+            numfailures,
+            write_type,
+        } => {
+            error.set_named_property("consistencies", consistency as u16)?;
+            error.set_named_property("received", received)?;
+            error.set_named_property("blockFor", required)?;
+            error.set_named_property("failures", numfailures)?;
+            error.set_named_property("writeType", write_type.as_str())?;
+        }
+        DbError::FunctionFailure {
+            keyspace,
+            function,
+            arg_types,
+        } => {
+            error.set_named_property("keyspace", keyspace)?;
+            error.set_named_property("functionName", function)?;
+            error.set_named_property("argTypes", arg_types)?;
+        }
+        DbError::AlreadyExists { keyspace, table } => {
+            error.set_named_property("keyspace", keyspace)?;
+            // Empty table name means that the keyspace already exists.
+            if !table.is_empty() {
+                error.set_named_property("table", table)?;
+            }
+        }
+        DbError::Unprepared { statement_id } => {
+            error.set_named_property("queryId", Buffer::from(statement_id.to_vec()))?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Batch options `logged` and `counter` were set to a combination that does not match
+/// any batch type: counter batches are never written to the batchlog.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Counter batches cannot be logged: set `logged` to false, or leave it unset, for counter batches"
+)]
+pub struct InvalidBatchTypeError;
+
+/// The `routingNames` option was given for an unprepared request. Unprepared statements cannot
+/// have named parameters, so the names cannot be mapped to the values of the routing key.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "The `routingNames` option is not supported, as unprepared statements cannot have named parameters: \
+    use the `routingIndexes` or `routingKey` options, or prepare the statement"
+)]
+pub struct RoutingNamesError;
+
+/// No response to the request was received within its client-side request timeout.
+/// Named after the corresponding error class of the JS driver.
+#[derive(Debug, thiserror::Error)]
+#[error("Request execution exceeded a client timeout of {}ms", .timeout.as_millis())]
+pub struct OperationTimedOutError {
+    timeout: Duration,
+}
+
+/// Nodes did not agree on the schema version within the schema agreement timeout.
+#[derive(Debug, thiserror::Error)]
+#[error("Schema agreement was not reached within {}ms", .timeout.as_millis())]
+pub struct SchemaAgreementTimeoutError {
+    timeout: Duration,
+}
+
+/// Classifies the error of a single request attempt.
+/// Connection errors mean that the node could not be reached.
+fn attempt_error_class(error: &RequestAttemptError) -> Option<DriverErrorClass> {
+    match error {
+        RequestAttemptError::DbError(db_error, _) => {
+            Some(DriverErrorClass::Response(db_error.clone()))
+        }
+        RequestAttemptError::BrokenConnectionError(_)
+        | RequestAttemptError::UnableToAllocStreamId => {
+            Some(DriverErrorClass::NoHostAvailable(Vec::new()))
+        }
+        _ => None,
+    }
+}
+
+fn prepare_error_class(error: &PrepareError) -> Option<DriverErrorClass> {
+    match error {
+        PrepareError::ConnectionPoolError(_) => Some(DriverErrorClass::NoHostAvailable(Vec::new())),
+        PrepareError::AllAttemptsFailed { first_attempt } => attempt_error_class(first_attempt),
+        _ => None,
+    }
+}
+
+fn schema_agreement_error_class(error: &SchemaAgreementError) -> Option<DriverErrorClass> {
+    match error {
+        SchemaAgreementError::ConnectionPoolError(_) => {
+            Some(DriverErrorClass::NoHostAvailable(Vec::new()))
+        }
+        SchemaAgreementError::PrepareError(error) => prepare_error_class(error),
+        SchemaAgreementError::RequestError(error) => attempt_error_class(error),
+        _ => None,
+    }
+}
+
+fn execution_error_class(error: &ExecutionError) -> Option<DriverErrorClass> {
+    match error {
+        ExecutionError::EmptyPlan | ExecutionError::ConnectionPoolError(_) => {
+            Some(DriverErrorClass::NoHostAvailable(Vec::new()))
+        }
+        ExecutionError::RequestTimeout(_)
+        | ExecutionError::UseKeyspaceError(UseKeyspaceError::RequestTimeout(_)) => {
+            Some(DriverErrorClass::OperationTimedOut)
+        }
+        ExecutionError::LastAttemptError(error)
+        | ExecutionError::UseKeyspaceError(UseKeyspaceError::RequestError(error)) => {
+            attempt_error_class(error)
+        }
+        ExecutionError::PrepareError(error) => prepare_error_class(error),
+        ExecutionError::SchemaAgreementError(error) => schema_agreement_error_class(error),
+        _ => None,
+    }
+}
+
+impl ConvertedError {
+    fn with_class(self, class: Option<DriverErrorClass>) -> Self {
+        ConvertedError { class, ..self }
+    }
+
+    /// Adds the errors of the attempts of the request, by address of the node each attempt was sent to,
+    /// if no node was able to execute the request.
+    /// Errors returned by the database are converted into `ResponseError`, and others into plain JS errors.
+    pub(crate) fn with_attempt_errors(
+        mut self,
+        attempts: impl IntoIterator<Item = (SocketAddr, RequestAttemptError)>,
+    ) -> Self {
+        if let Some(DriverErrorClass::NoHostAvailable(inner_errors)) = &mut self.class {
+            inner_errors.extend(attempts.into_iter().map(|(node, error)| {
+                let class = attempt_error_class(&error)
+                    .filter(|class| matches!(class, DriverErrorClass::Response(_)));
+                (node, ConvertedError::from(error).with_class(class))
+            }));
+        }
+        self
+    }
+
+    /// Converts the error of a single request attempt,
+    /// classifying it into the error classes of the JS driver.
+    pub(crate) fn from_attempt_error(error: RequestAttemptError) -> Self {
+        let class = attempt_error_class(&error);
+        ConvertedError::from(error).with_class(class)
+    }
+
+    /// Converts an error about invalid options of a request into `ArgumentError`.
+    pub(crate) fn argument(error: impl Error) -> Self {
+        ConvertedError::from(error).with_class(Some(DriverErrorClass::Argument))
+    }
+
+    /// Converts an error returned by request execution in the Rust driver,
+    /// classifying it into the error classes of the JS driver.
+    pub(crate) fn from_execution_error(error: ExecutionError) -> Self {
+        let class = execution_error_class(&error);
+        match error {
+            ExecutionError::RequestTimeout(timeout) => OperationTimedOutError { timeout }.into(),
+            ExecutionError::SchemaAgreementError(error) => Self::from_schema_agreement_error(error),
+            error => error.into(),
+        }
+        .with_class(class)
+    }
+
+    /// Converts an error returned by statement preparation in the Rust driver,
+    /// classifying it into the error classes of the JS driver.
+    pub(crate) fn from_prepare_error(error: PrepareError) -> Self {
+        let class = prepare_error_class(&error);
+        ConvertedError::from(error).with_class(class)
+    }
+
+    /// Converts an error returned by a schema agreement check in the Rust driver,
+    /// giving a distinct name to the timeout.
+    pub(crate) fn from_schema_agreement_error(error: SchemaAgreementError) -> Self {
+        let class = schema_agreement_error_class(&error);
+        match error {
+            SchemaAgreementError::Timeout(timeout) => {
+                ConvertedError::from(SchemaAgreementTimeoutError { timeout })
+            }
+            error => error.into(),
+        }
+        .with_class(class)
+    }
+}
+
 /// Allows to run a block of code that returns Result<T, ConvertedError>,
 /// with automatic conversion to JsResult<T>. This allows to use the `?` operator,
 /// while still returning JsResult<T> from the function.
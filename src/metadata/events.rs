@@ -0,0 +1,281 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use scylla::client::caching_session::CachingSession;
+use scylla::cluster::ClusterState;
+use scylla::cluster::metadata::Keyspace;
+use uuid::Uuid;
+
+use crate::utils::js_callback::JsCallback;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+/// Interval, at which the cluster state and the connectivity of the nodes are checked for changes.
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+// Change of the topology, or of the schema of the cluster, passed to JS.
+// Values of change are "created", "updated" and "dropped",
+// values of target are "keyspace", "table", "view" and "udt". Name is null for keyspaces.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub enum ClusterEventObj
+where VariantName: kind {
+    HostAdd {
+        host_id, hostId: String,
+    } = "hostAdd",
+    HostRemove {
+        host_id, hostId: String,
+    } = "hostRemove",
+    HostUp {
+        host_id, hostId: String,
+    } = "hostUp",
+    HostDown {
+        host_id, hostId: String,
+    } = "hostDown",
+    SchemaChange {
+        change, change: String,
+        target, target: String,
+        keyspace, keyspace: String,
+        name, name: Option<String>,
+    } = "schemaChange",
+});
+
+/// JS function receiving the changes of the cluster, passed with the session options.
+pub type ClusterEventsCallback = JsCallback<Vec<ClusterEventObj>, ()>;
+
+/// Cluster state, with the connectivity of each of its nodes, at a given point in time.
+struct ClusterView {
+    state: Arc<ClusterState>,
+    /// Whether the driver has any open connection to the node, by host id.
+    connected: HashMap<Uuid, bool>,
+}
+
+impl ClusterView {
+    fn new(session: &CachingSession) -> Self {
+        let state = session.get_session().get_cluster_state();
+        let connected = state
+            .get_nodes_info()
+            .iter()
+            .map(|node| (node.host_id, node.is_connected()))
+            .collect();
+        ClusterView { state, connected }
+    }
+
+    /// Returns the changes between the previous and this view of the cluster.
+    fn changes_since(&self, previous: &ClusterView) -> Vec<ClusterEventObj> {
+        let mut events = Vec::new();
+        for (&host_id, &connected) in &self.connected {
+            let host_id_str = || host_id.to_string();
+            match previous.connected.get(&host_id) {
+                None => events.push(ClusterEventObj::HostAdd {
+                    host_id: host_id_str(),
+                }),
+                Some(&was_connected) if was_connected != connected => events.push(if connected {
+                    ClusterEventObj::HostUp {
+                        host_id: host_id_str(),
+                    }
+                } else {
+                    ClusterEventObj::HostDown {
+                        host_id: host_id_str(),
+                    }
+                }),
+                Some(_) => (),
+            }
+        }
+        events.extend(
+            previous
+                .connected
+                .keys()
+                .filter(|host_id| !self.connected.contains_key(host_id))
+                .map(|host_id| ClusterEventObj::HostRemove {
+                    host_id: host_id.to_string(),
+                }),
+        );
+        // The schema can change only when the Rust driver produces a new cluster state.
+        if !Arc::ptr_eq(&self.state, &previous.state) {
+            schema_changes(&previous.state, &self.state, &mut events);
+        }
+        events
+    }
+}
+
+fn schema_change(
+    change: &str,
+    target: &str,
+    keyspace: &str,
+    name: Option<&str>,
+) -> ClusterEventObj {
+    ClusterEventObj::SchemaChange {
+        change: change.to_owned(),
+        target: target.to_owned(),
+        keyspace: keyspace.to_owned(),
+        name: name.map(str::to_owned),
+    }
+}
+
+/// Adds the changes between the elements of the schema of a keyspace (tables, views or udts) to the events.
+/// Elements of created and dropped keyspaces are reported as created and dropped as well.
+fn named_changes<T: PartialEq>(
+    previous: Option<&HashMap<String, T>>,
+    next: Option<&HashMap<String, T>>,
+    target: &str,
+    keyspace: &str,
+    events: &mut Vec<ClusterEventObj>,
+) {
+    let empty = HashMap::new();
+    let (previous, next) = (previous.unwrap_or(&empty), next.unwrap_or(&empty));
+    for (name, element) in next {
+        match previous.get(name) {
+            None => events.push(schema_change("created", target, keyspace, Some(name))),
+            Some(previous) if previous != element => {
+                events.push(schema_change("updated", target, keyspace, Some(name)))
+            }
+            Some(_) => (),
+        }
+    }
+    events.extend(
+        previous
+            .keys()
+            .filter(|name| !next.contains_key(*name))
+            .map(|name| schema_change("dropped", target, keyspace, Some(name))),
+    );
+}
+
+/// Adds the changes of the schema between the previous and the next cluster state to the events.
+fn schema_changes(previous: &ClusterState, next: &ClusterState, events: &mut Vec<ClusterEventObj>) {
+    let previous_keyspaces: HashMap<&str, &Keyspace> = previous.keyspaces_iter().collect();
+    let next_keyspaces: HashMap<&str, &Keyspace> = next.keyspaces_iter().collect();
+    let names: HashSet<&str> = previous_keyspaces
+        .keys()
+        .chain(next_keyspaces.keys())
+        .copied()
+        .collect();
+    for name in names {
+        let previous = previous_keyspaces.get(name).copied();
+        let next = next_keyspaces.get(name).copied();
+        let keyspace_change = match (previous, next) {
+            (None, _) => Some("created"),
+            (_, None) => Some("dropped"),
+            (Some(previous), Some(next))
+                if previous.strategy != next.strategy
+                    || previous.durable_writes != next.durable_writes =>
+            {
+                Some("updated")
+            }
+            _ => None,
+        };
+        // A keyspace is reported before its elements, unless it was dropped.
+        let elements_start = events.len();
+        named_changes(
+            previous.map(|keyspace| &keyspace.tables),
+            next.map(|keyspace| &keyspace.tables),
+            "table",
+            name,
+            events,
+        );
+        named_changes(
+            previous.map(|keyspace| &keyspace.views),
+            next.map(|keyspace| &keyspace.views),
+            "view",
+            name,
+            events,
+        );
+        named_changes(
+            previous.map(|keyspace| &keyspace.user_defined_types),
+            next.map(|keyspace| &keyspace.user_defined_types),
+            "udt",
+            name,
+            events,
+        );
+        match keyspace_change {
+            Some("dropped") => events.push(schema_change("dropped", "keyspace", name, None)),
+            Some(change) => events.insert(
+                elements_start,
+                schema_change(change, "keyspace", name, None),
+            ),
+            None => (),
+        }
+    }
+}
+
+/// Watches the cluster, passing its changes to JS, until the session is dropped.
+///
+/// The Rust driver does not notify about the changes of the cluster, so the cluster state
+/// and the connectivity of the nodes are checked every `WATCH_INTERVAL`, and compared
+/// with their previous values. Changes made between two checks, that cancel each other
+/// (e.g. a node going down and up again), are not reported.
+pub(crate) async fn watch_cluster(session: Weak<CachingSession>, callback: ClusterEventsCallback) {
+    let Some(mut view) = session.upgrade().map(|session| ClusterView::new(&session)) else {
+        return;
+    };
+    loop {
+        tokio::time::sleep(WATCH_INTERVAL).await;
+        let Some(next) = session.upgrade().map(|session| ClusterView::new(&session)) else {
+            return;
+        };
+        let events = next.changes_since(&view);
+        if !events.is_empty() {
+            callback.call(events);
+        }
+        view = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tables(tables: &[(&str, i32)]) -> HashMap<String, i32> {
+        tables
+            .iter()
+            .map(|(name, definition)| (name.to_string(), *definition))
+            .collect()
+    }
+
+    /// Returns the change and the name of each schema change, sorted by name.
+    fn changes(events: Vec<ClusterEventObj>) -> Vec<(String, Option<String>)> {
+        let mut changes: Vec<_> = events
+            .into_iter()
+            .map(|event| match event {
+                ClusterEventObj::SchemaChange { change, name, .. } => (change, name),
+                _ => panic!("Expected only schema changes"),
+            })
+            .collect();
+        changes.sort_by(|a, b| a.1.cmp(&b.1));
+        changes
+    }
+
+    #[test]
+    fn named_changes_of_existing_keyspace() {
+        let mut events = Vec::new();
+        named_changes(
+            Some(&tables(&[("dropped", 1), ("same", 1), ("updated", 1)])),
+            Some(&tables(&[("created", 1), ("same", 1), ("updated", 2)])),
+            "table",
+            "ks",
+            &mut events,
+        );
+        assert_eq!(
+            changes(events),
+            vec![
+                ("created".to_owned(), Some("created".to_owned())),
+                ("dropped".to_owned(), Some("dropped".to_owned())),
+                ("updated".to_owned(), Some("updated".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn named_changes_of_created_and_dropped_keyspace() {
+        let mut events = Vec::new();
+        named_changes(None, Some(&tables(&[("a", 1)])), "table", "ks", &mut events);
+        named_changes(Some(&tables(&[("b", 1)])), None, "table", "ks", &mut events);
+        assert_eq!(
+            changes(events),
+            vec![
+                ("created".to_owned(), Some("a".to_owned())),
+                ("dropped".to_owned(), Some("b".to_owned())),
+            ]
+        );
+    }
+}
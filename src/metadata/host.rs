@@ -1,10 +1,19 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
 
+use futures::TryStreamExt;
 use napi::Env;
-use napi::bindgen_prelude::FnArgs;
+use napi::bindgen_prelude::{FnArgs, spawn};
+use scylla::client::caching_session::CachingSession;
+use scylla::client::execution_profile::{ExecutionProfile, ExecutionProfileHandle};
+use scylla::client::session::Session;
 use scylla::cluster::{ClusterState, Node};
+use scylla::policies::load_balancing::{NodeIdentifier, SingleTargetLoadBalancingPolicy};
+use scylla::policies::retry::FallthroughRetryPolicy;
+use scylla::statement::{Consistency, Statement};
+use uuid::Uuid;
 
 use crate::errors::{ConvertedError, JsResult, with_custom_error_sync};
 use crate::metadata::state::ClusterSnapshot;
@@ -17,34 +26,72 @@ use crate::utils::js_instance::JsInstance;
 use crate::utils::napi_ref::NapiRef;
 use crate::utils::to_napi_obj::{CopyableBuffer, NamedMap};
 
+const RELEASE_VERSION_QUERY: &str = "SELECT release_version FROM system.local WHERE key = 'local'";
+
+/// Time after which reading the release version of a node fails.
+const RELEASE_VERSION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time after a failed read of the release version of a node, before the version is read again.
+const RELEASE_VERSION_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// JS `Host` objects, each pinned on its own, keyed by host id.
+pub(crate) type HostsById = HashMap<Uuid, NapiRef<js_constructible_class::Host>>;
+
 /// Builds a JS `Host` object for every node known via `cluster_state` and collects them into a
-/// single JS `HostMap`, pinning only that map with a `NapiRef`.
+/// single JS `HostMap`, pinning that map with a `NapiRef`.
 ///
 /// Pinning the `HostMap` alone (rather than each `Host` individually) is enough to keep every
 /// `Host` alive, since the map strongly references all of them. It also means
 /// `SessionWrapper::get_all_hosts` hands back one already-assembled object instead of rebuilding
 /// a map on the JS side per call, for as long as the cluster state doesn't change.
+///
+/// Each `Host` is additionally pinned on its own and returned keyed by host id, so that other
+/// Rust code (e.g. replica lookups) can hand back the very same `Host` instances found in the map.
 pub(crate) fn cache_host_map(
     cluster_state: &ClusterState,
+    release_versions: &Arc<ReleaseVersions>,
     env: &Env,
-) -> napi::Result<NapiRef<js_constructible_class::HostMap>> {
-    let entries = cluster_state
-        .get_nodes_info()
-        .iter()
-        .map(|node| {
-            let host = build_host(env, host_ctor_args(node, env)?)?;
-            let key = node.host_id.simple().to_string();
-            Ok((key, host))
-        })
-        .collect::<napi::Result<HashMap<_, _>>>()?;
+) -> napi::Result<(NapiRef<js_constructible_class::HostMap>, HostsById)> {
+    release_versions.retain_nodes(cluster_state);
+    let mut tokens = node_tokens(cluster_state);
+    let mut hosts = HashMap::new();
+    let mut entries = HashMap::new();
+    for node in cluster_state.get_nodes_info() {
+        let args = host_ctor_args(
+            node,
+            release_versions,
+            tokens.remove(&node.host_id).unwrap_or_default(),
+            env,
+        )?;
+        let host = NapiRef::new(env, build_host(env, args)?)?;
+        entries.insert(node.host_id.simple().to_string(), host.get(env)?);
+        hosts.insert(node.host_id, host);
+    }
 
     let items = NamedMap::new(entries);
     let host_map = build_host_map(env, FnArgs::from((items,)))?;
-    NapiRef::new(env, host_map)
+    Ok((NapiRef::new(env, host_map)?, hosts))
+}
+
+/// Returns the tokens of the token ring owned by each node, as strings, keyed by host id.
+fn node_tokens(cluster_state: &ClusterState) -> HashMap<Uuid, Vec<String>> {
+    let mut tokens: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for (token, node) in cluster_state.replica_locator().ring().iter() {
+        tokens
+            .entry(node.host_id)
+            .or_default()
+            .push(token.value().to_string());
+    }
+    tokens
 }
 
 /// Builds the arguments passed to the JS Host constructor for the given node.
-fn host_ctor_args<'a>(node: &'a Arc<Node>, env: &'a Env) -> napi::Result<HostCtorArgs<'a>> {
+fn host_ctor_args<'a>(
+    node: &'a Arc<Node>,
+    release_versions: &Arc<ReleaseVersions>,
+    tokens: Vec<String>,
+    env: &'a Env,
+) -> napi::Result<HostCtorArgs<'a>> {
     let address = SocketAddr::new(node.address.ip(), node.address.port());
     let address = build_socket_address(env, FnArgs::from((SocketAddrWrapper::from(address),)))?;
 
@@ -53,9 +100,175 @@ fn host_ctor_args<'a>(node: &'a Arc<Node>, env: &'a Env) -> napi::Result<HostCto
         node.datacenter.as_deref(),
         node.rack.as_deref(),
         CopyableBuffer::new(node.host_id.as_bytes().as_slice()),
+        HostStateWrapper {
+            node: node.clone(),
+            release_versions: release_versions.clone(),
+        },
+        tokens,
     )))
 }
 
+/// State of a node, that can change without the Rust driver producing a new cluster state.
+///
+/// JS `Host` objects read it through this object whenever it is requested,
+/// so they do not need to be rebuilt when the state changes.
+#[napi]
+pub struct HostStateWrapper {
+    node: Arc<Node>,
+    release_versions: Arc<ReleaseVersions>,
+}
+
+#[napi]
+impl HostStateWrapper {
+    /// Whether the driver has any open connection to the node.
+    #[napi]
+    pub fn is_up(&self) -> bool {
+        self.node.is_connected()
+    }
+
+    /// Number of shards of the node. Known only for ScyllaDB nodes, once the driver connects to them.
+    #[napi]
+    pub fn shard_count(&self) -> Option<u32> {
+        self.node
+            .sharder()
+            .map(|sharder| u32::from(sharder.nr_shards.get()))
+    }
+
+    /// Release version of the node, if it is already known.
+    ///
+    /// The version is read from the node in the background, when it is requested for the first time
+    /// since the driver connected to the node, so it is `null` until the read completes.
+    #[napi]
+    pub fn release_version(&self) -> Option<String> {
+        self.release_versions.get(&self.node)
+    }
+}
+
+/// Release version of a node, and the state of reading it.
+#[derive(Default)]
+struct ReleaseVersion {
+    version: Option<String>,
+    /// Whether the version was read since the driver connected to the node. The version can change
+    /// only when the node restarts, so it is read again once the driver reconnects to the node.
+    is_current: bool,
+    is_reading: bool,
+    /// Time of the last failed read, if the version was not read since.
+    failed_at: Option<Instant>,
+}
+
+/// Release versions of the nodes, keyed by host id.
+///
+/// The Rust driver does not read the versions of the nodes, so they are read
+/// from the `system.local` table of each node, only when requested.
+pub(crate) struct ReleaseVersions {
+    session: Weak<CachingSession>,
+    /// Profile of the reads, independent of the profiles of the client: the reads
+    /// are not retried, and their consistency and timeout are fixed.
+    profile: ExecutionProfileHandle,
+    versions: Mutex<HashMap<Uuid, ReleaseVersion>>,
+}
+
+impl ReleaseVersions {
+    pub(crate) fn new(session: Weak<CachingSession>) -> Self {
+        let profile = ExecutionProfile::builder()
+            .consistency(Consistency::One)
+            .retry_policy(Arc::new(FallthroughRetryPolicy::new()))
+            .speculative_execution_policy(None)
+            // The timeout is applied on top of the request, not to count it as a request timeout in the metrics.
+            .request_timeout(None)
+            .build();
+        ReleaseVersions {
+            session,
+            profile: profile.into_handle(),
+            versions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Uuid, ReleaseVersion>> {
+        self.versions
+            .lock()
+            .expect("poisoning impossible due to process-aborting panics")
+    }
+
+    /// Returns the known release version of the node, starting to read it first,
+    /// if it was not read since the driver connected to the node.
+    fn get(self: &Arc<Self>, node: &Arc<Node>) -> Option<String> {
+        let mut versions = self.lock();
+        let entry = versions.entry(node.host_id).or_default();
+        if !node.is_connected() {
+            entry.is_current = false;
+        } else if !entry.is_current
+            && !entry.is_reading
+            && entry
+                .failed_at
+                .is_none_or(|failed_at| failed_at.elapsed() >= RELEASE_VERSION_RETRY_INTERVAL)
+        {
+            entry.is_reading = true;
+            spawn(self.clone().read(node.clone()));
+        }
+        entry.version.clone()
+    }
+
+    /// Reads the release version of the node, storing it once read.
+    async fn read(self: Arc<Self>, node: Arc<Node>) {
+        let version = match self.session.upgrade() {
+            Some(session) => {
+                let read = self.read_release_version(session.get_session(), &node);
+                tokio::time::timeout(RELEASE_VERSION_TIMEOUT, read)
+                    .await
+                    .ok()
+                    .flatten()
+            }
+            None => None,
+        };
+        let mut versions = self.lock();
+        let entry = versions.entry(node.host_id).or_default();
+        entry.is_reading = false;
+        match version {
+            Some(version) => {
+                entry.version = Some(version);
+                entry.is_current = true;
+                entry.failed_at = None;
+            }
+            None => entry.failed_at = Some(Instant::now()),
+        }
+    }
+
+    /// Reads the release version of the node from its `system.local` table.
+    ///
+    /// The version is read with the pager, as the requests it executes are not counted
+    /// in the requests and errors of the metrics of the driver.
+    async fn read_release_version(&self, session: &Session, node: &Arc<Node>) -> Option<String> {
+        let mut statement = Statement::new(RELEASE_VERSION_QUERY);
+        statement.set_is_idempotent(true);
+        statement.set_execution_profile_handle(Some(self.profile.clone()));
+        statement.set_load_balancing_policy(Some(SingleTargetLoadBalancingPolicy::new(
+            NodeIdentifier::Node(node.clone()),
+            None,
+        )));
+        session
+            .query_iter(statement, &[])
+            .await
+            .ok()?
+            .rows_stream::<(Option<String>,)>()
+            .ok()?
+            .try_next()
+            .await
+            .ok()??
+            .0
+    }
+
+    /// Forgets the release versions of the nodes no longer part of the cluster.
+    fn retain_nodes(&self, cluster_state: &ClusterState) {
+        let nodes: HashSet<Uuid> = cluster_state
+            .get_nodes_info()
+            .iter()
+            .map(|node| node.host_id)
+            .collect();
+        self.lock().retain(|host_id, _| nodes.contains(host_id));
+    }
+}
+
 #[napi]
 impl SessionWrapper {
     /// Returns all nodes known by the Rust driver as a `HostMap`, keyed by address, for the
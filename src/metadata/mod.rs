@@ -1,2 +1,6 @@
+pub mod events;
 pub mod host;
+pub mod schema;
 pub mod state;
+pub mod token_ring;
+pub mod tracing;
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use napi::Env;
+use napi::bindgen_prelude::{Object, ToNapiValue};
+use scylla::cluster::ClusterState;
+use scylla::cluster::metadata::{
+    Column, ColumnKind, Keyspace, MaterializedView, Strategy, Table, UserDefinedType,
+};
+
+use crate::errors::{ConvertedError, JsResult, with_custom_error_sync};
+use crate::metadata::state::ClusterSnapshot;
+use crate::session::SessionWrapper;
+use crate::types::type_wrappers::ComplexType;
+use crate::utils::js_ctor::js_constructible_class;
+use crate::utils::js_instance::JsInstance;
+use crate::utils::napi_ref::NapiRef;
+use crate::utils::to_napi_obj::{NamedMap, define_rust_to_js_convertible_object};
+
+// Shapes of the objects below mirror the classes defined in lib/metadata.
+// Column types are passed as `ComplexType`, and are converted into `ColumnInfo` on the JS side.
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct ColumnMetadataObj {
+    typ, type: ComplexType<'static>,
+    kind, kind: u32,
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct TableMetadataObj {
+    columns, columns: NamedMap<String, ColumnMetadataObj>,
+    partition_key, partitionKey: Vec<String>,
+    clustering_key, clusteringKey: Vec<String>,
+    partitioner, partitioner: Option<String>,
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct MaterializedViewObj {
+    view_metadata, viewMetadata: TableMetadataObj,
+    table_name, tableName: String,
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct UdtFieldObj {
+    name, name: String,
+    typ, type: ComplexType<'static>,
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct UdtObj {
+    name, name: String,
+    keyspace, keyspace: String,
+    fields, fields: Vec<UdtFieldObj>,
+});
+
+define_rust_to_js_convertible_object!(
+pub enum StrategyObj where
+    VariantName: kind,
+{
+    SimpleStrategy {
+        replication_factor, replicationFactor: u32,
+    } = 0,
+    NetworkTopologyStrategy {
+        datacenter_repfactors, datacenterRepfactors: NamedMap<String, u32>,
+    } = 1,
+    LocalStrategy = 2,
+    Other {
+        name, name: String,
+        data, data: NamedMap<String, String>,
+    } = 3,
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct KeyspaceMetadataObj {
+    strategy, strategy: StrategyObj,
+    durable_writes, durableWrites: bool,
+    tables, tables: NamedMap<String, TableMetadataObj>,
+    views, views: NamedMap<String, MaterializedViewObj>,
+    udts, udts: NamedMap<String, UdtObj>,
+});
+
+/// Values of the `ColumnKind` enum from lib/metadata/table-metadata.js.
+fn column_kind_to_js(kind: &ColumnKind) -> u32 {
+    match kind {
+        ColumnKind::Regular => 0,
+        ColumnKind::Static => 1,
+        ColumnKind::Clustering => 2,
+        ColumnKind::PartitionKey => 3,
+        // Kinds added in newer versions of the Rust driver.
+        _ => 4,
+    }
+}
+
+/// Replication factors never come close to `u32::MAX`, so we saturate instead of failing.
+fn replication_factor_to_js(rf: usize) -> u32 {
+    rf.try_into().unwrap_or(u32::MAX)
+}
+
+impl From<&Strategy> for StrategyObj {
+    fn from(strategy: &Strategy) -> Self {
+        match strategy {
+            Strategy::SimpleStrategy { replication_factor } => StrategyObj::SimpleStrategy {
+                replication_factor: replication_factor_to_js(*replication_factor),
+            },
+            Strategy::NetworkTopologyStrategy {
+                datacenter_repfactors,
+            } => StrategyObj::NetworkTopologyStrategy {
+                datacenter_repfactors: NamedMap::new(
+                    datacenter_repfactors
+                        .iter()
+                        .map(|(dc, rf)| (dc.clone(), replication_factor_to_js(*rf)))
+                        .collect(),
+                ),
+            },
+            Strategy::LocalStrategy => StrategyObj::LocalStrategy,
+            Strategy::Other { name, data } => StrategyObj::Other {
+                name: name.clone(),
+                data: NamedMap::new(data.clone()),
+            },
+            // Strategies added in newer versions of the Rust driver.
+            other => StrategyObj::Other {
+                name: format!("{other:?}"),
+                data: NamedMap::new(HashMap::new()),
+            },
+        }
+    }
+}
+
+impl From<&Column> for ColumnMetadataObj {
+    fn from(column: &Column) -> Self {
+        ColumnMetadataObj {
+            typ: ComplexType::new_owned(column.typ.clone()),
+            kind: column_kind_to_js(&column.kind),
+        }
+    }
+}
+
+impl From<&Table> for TableMetadataObj {
+    fn from(table: &Table) -> Self {
+        TableMetadataObj {
+            columns: NamedMap::new(
+                table
+                    .columns
+                    .iter()
+                    .map(|(name, column)| (name.clone(), column.into()))
+                    .collect(),
+            ),
+            partition_key: table.partition_key.clone(),
+            clustering_key: table.clustering_key.clone(),
+            partitioner: table.partitioner.clone(),
+        }
+    }
+}
+
+impl From<&MaterializedView> for MaterializedViewObj {
+    fn from(view: &MaterializedView) -> Self {
+        MaterializedViewObj {
+            view_metadata: (&view.view_metadata).into(),
+            table_name: view.base_table_name.clone(),
+        }
+    }
+}
+
+impl From<&UserDefinedType<'static>> for UdtObj {
+    fn from(udt: &UserDefinedType<'static>) -> Self {
+        UdtObj {
+            name: udt.name.to_string(),
+            keyspace: udt.keyspace.to_string(),
+            fields: udt
+                .field_types
+                .iter()
+                .map(|(name, typ)| UdtFieldObj {
+                    name: name.to_string(),
+                    typ: ComplexType::new_owned(typ.clone()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Keyspace> for KeyspaceMetadataObj {
+    fn from(keyspace: &Keyspace) -> Self {
+        fn convert_all<'a, T: 'a, O>(
+            items: impl Iterator<Item = (&'a String, T)>,
+            f: impl Fn(T) -> O,
+        ) -> HashMap<String, O> {
+            items.map(|(name, item)| (name.clone(), f(item))).collect()
+        }
+
+        KeyspaceMetadataObj {
+            strategy: (&keyspace.strategy).into(),
+            durable_writes: keyspace.durable_writes,
+            tables: NamedMap::new(convert_all(keyspace.tables.iter(), TableMetadataObj::from)),
+            views: NamedMap::new(convert_all(
+                keyspace.views.iter(),
+                MaterializedViewObj::from,
+            )),
+            udts: NamedMap::new(convert_all(keyspace.user_defined_types.iter(), |udt| {
+                UdtObj::from(udt.as_ref())
+            })),
+        }
+    }
+}
+
+/// Builds a plain JS object holding the schema metadata of every keyspace known via
+/// `cluster_state`, keyed by keyspace name, and pins it with a `NapiRef`.
+///
+/// Similarly to `cache_host_map`, pinning only the outer object is enough to keep every nested
+/// keyspace, table, view and UDT object alive.
+pub(crate) fn cache_keyspaces(
+    cluster_state: &ClusterState,
+    env: &Env,
+) -> napi::Result<NapiRef<js_constructible_class::KeyspaceMetadataMap>> {
+    let keyspaces: NamedMap<String, KeyspaceMetadataObj> = NamedMap::new(
+        cluster_state
+            .keyspaces_iter()
+            .map(|(name, keyspace)| (name.to_owned(), keyspace.into()))
+            .collect(),
+    );
+
+    let raw = unsafe { ToNapiValue::to_napi_value(env.raw(), keyspaces) }?;
+    NapiRef::new(
+        env,
+        JsInstance::from_object(Object::from_raw(env.raw(), raw)),
+    )
+}
+
+#[napi]
+impl SessionWrapper {
+    /// Returns the schema metadata of all keyspaces known by the Rust driver, keyed by keyspace
+    /// name, for the current cluster state (refreshing the cached cluster state snapshot first,
+    /// if the Rust driver has produced a newer one since the last access). The same JS object
+    /// is returned across calls, for as long as the underlying cluster state doesn't change.
+    #[napi(ts_return_type = "Record<string, KeyspaceMetadataObj>")]
+    pub fn get_keyspaces<'env>(
+        &self,
+        env: &'env Env,
+    ) -> JsResult<JsInstance<'env, js_constructible_class::KeyspaceMetadataMap>> {
+        with_custom_error_sync(|| {
+            self.with_cluster_snapshot(env, |cluster_snapshot: &ClusterSnapshot| {
+                cluster_snapshot
+                    .keyspaces(env)?
+                    .get(env)
+                    .map_err(ConvertedError::from)
+            })
+        })
+    }
+}
@@ -1,7 +1,9 @@
-use crate::metadata::host::cache_host_map;
+use crate::metadata::host::{HostsById, ReleaseVersions, cache_host_map};
+use crate::metadata::schema::cache_keyspaces;
 use crate::utils::js_ctor::js_constructible_class;
 use crate::utils::napi_ref::NapiRef;
 use napi::Env;
+use std::cell::OnceCell;
 use std::sync::Arc;
 
 /// A snapshot of the cluster's topology and schema metadata, as known by the driver
@@ -19,13 +21,45 @@ pub(crate) struct ClusterSnapshot {
     /// The `NapiRef` releases the JS object it pins automatically when dropped (i.e. when this
     /// `ClusterSnapshot` itself is dropped, or replaced by a fresher one), so no custom finalizer
     /// is needed here to avoid leaking a `HostMap` on every cluster state refresh. Pinning the map
-    /// keeps every `Host` it holds alive, so the hosts need no separate `NapiRef`s.
+    /// keeps every `Host` it holds alive.
     pub(crate) host_map: NapiRef<js_constructible_class::HostMap>,
+    /// The same `Host` objects as in `host_map`, keyed by host id, for looking up the JS `Host`
+    /// of a given Rust `Node` (e.g. when returning replicas of a token).
+    pub(crate) hosts: HostsById,
+    /// Schema metadata of all keyspaces known at the time this snapshot was created, keyed by
+    /// keyspace name.
+    ///
+    /// Unlike `host_map`, this is built lazily, on first access: schema metadata can be large,
+    /// and most users never read it, so there is no point in rebuilding it on every refresh.
+    /// `OnceCell` is enough here, as the snapshot is only ever accessed from the JS thread.
+    keyspaces: OnceCell<NapiRef<js_constructible_class::KeyspaceMetadataMap>>,
 }
 
 impl ClusterSnapshot {
-    pub(crate) fn new(inner: Arc<scylla::cluster::ClusterState>, env: &Env) -> napi::Result<Self> {
-        let host_map = cache_host_map(&inner, env)?;
-        Ok(ClusterSnapshot { inner, host_map })
+    pub(crate) fn new(
+        inner: Arc<scylla::cluster::ClusterState>,
+        release_versions: &Arc<ReleaseVersions>,
+        env: &Env,
+    ) -> napi::Result<Self> {
+        let (host_map, hosts) = cache_host_map(&inner, release_versions, env)?;
+        Ok(ClusterSnapshot {
+            inner,
+            host_map,
+            hosts,
+            keyspaces: OnceCell::new(),
+        })
+    }
+
+    /// Returns the cached schema metadata of all keyspaces, building it first if this is the
+    /// first access since this snapshot was created.
+    pub(crate) fn keyspaces(
+        &self,
+        env: &Env,
+    ) -> napi::Result<&NapiRef<js_constructible_class::KeyspaceMetadataMap>> {
+        if let Some(keyspaces) = self.keyspaces.get() {
+            return Ok(keyspaces);
+        }
+        let keyspaces = cache_keyspaces(&self.inner, env)?;
+        Ok(self.keyspaces.get_or_init(|| keyspaces))
     }
 }
@@ -0,0 +1,230 @@
+use napi::Env;
+use napi::bindgen_prelude::{BigInt, Buffer};
+use scylla::cluster::metadata::Keyspace;
+use scylla::cluster::{ClusterState, Node};
+use scylla::routing::Token;
+use scylla::routing::partitioner::{Partitioner, PartitionerName};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::errors::{
+    ConvertedError, ConvertedResult, JsResult, make_js_error, with_custom_error_sync,
+};
+use crate::metadata::state::ClusterSnapshot;
+use crate::session::SessionWrapper;
+use crate::utils::bigint_to_i64;
+use crate::utils::js_ctor::js_constructible_class;
+use crate::utils::js_instance::JsInstance;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+// Table name used when replicas are looked up for a keyspace only. No table can be named like this,
+// so the lookup always follows the keyspace replication strategy over the token ring (vnodes).
+const NO_TABLE: &str = "";
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct TokenRangeObj {
+    start, start: BigInt,
+    end, end: BigInt,
+});
+
+impl TokenRangeObj {
+    fn new(start: i64, end: i64) -> Self {
+        TokenRangeObj {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+}
+
+/// Ownership of tablet-based tables is defined per table, not per keyspace,
+/// so replicas cannot be looked up for a tablet-based keyspace without a table.
+fn resolve_table<'a>(
+    keyspace_meta: &Keyspace,
+    keyspace: &str,
+    table: Option<&'a str>,
+) -> ConvertedResult<&'a str> {
+    match table {
+        Some(table) => Ok(table),
+        None if keyspace_meta.tablet_based => Err(make_js_error(format!(
+            "Keyspace {keyspace} uses tablets, a table name is required to find replicas"
+        ))
+        .into()),
+        None => Ok(NO_TABLE),
+    }
+}
+
+/// Returns the `(start, end)` token values of all ranges of the token ring.
+///
+/// Each range is start-exclusive and end-inclusive, and the first range wraps around the ring.
+/// A ring with a single token is represented as a single range from the minimum token
+/// to the minimum token, which covers the whole ring.
+fn ring_ranges(cluster_state: &ClusterState) -> Vec<(i64, i64)> {
+    let tokens: Vec<i64> = cluster_state
+        .replica_locator()
+        .ring()
+        .iter()
+        .map(|(token, _)| token.value())
+        .collect();
+    match tokens.as_slice() {
+        [] => vec![],
+        [_] => vec![(i64::MIN, i64::MIN)],
+        [.., last] => std::iter::once(*last)
+            .chain(tokens.iter().copied())
+            .zip(tokens.iter().copied())
+            .collect(),
+    }
+}
+
+/// Replicas of the given token, for the given table (or keyspace only, when `table` is `NO_TABLE`).
+///
+/// For tablet-based tables, the driver learns about tablets lazily, from responses to requests
+/// sent to that table. Until it does, no replicas are known for a given token.
+fn token_replicas(
+    cluster_state: &ClusterState,
+    keyspace: &str,
+    table: &str,
+    token: Token,
+) -> Vec<Arc<Node>> {
+    cluster_state
+        .get_token_endpoints(keyspace, table, token)
+        .into_iter()
+        .map(|(node, _shard)| node)
+        .collect()
+}
+
+#[napi]
+impl SessionWrapper {
+    /// Returns the hosts that are replicas of the given token, for the given keyspace,
+    /// or `null` if the keyspace is not known.
+    ///
+    /// The `table` is required for tablet-based keyspaces, and ignored otherwise.
+    /// Returned hosts are the same `Host` objects as in the `HostMap` returned by `getAllHosts`.
+    #[napi(ts_return_type = "Array<import('../lib/host').Host> | null")]
+    pub fn get_replicas_by_token<'env>(
+        &self,
+        env: &'env Env,
+        keyspace: String,
+        table: Option<String>,
+        token: BigInt,
+    ) -> JsResult<Option<Vec<JsInstance<'env, js_constructible_class::Host>>>> {
+        with_custom_error_sync(|| {
+            let token = Token::new(bigint_to_i64(token, "Token must be a 64-bit integer")?);
+            self.with_cluster_snapshot(env, |cluster_snapshot: &ClusterSnapshot| {
+                cluster_snapshot.replicas(env, &keyspace, table.as_deref(), token)
+            })
+        })
+    }
+
+    /// Returns the hosts that are replicas of the given routing key (serialized partition key),
+    /// for the given keyspace, or `null` if the keyspace is not known.
+    ///
+    /// The token is computed with the partitioner of the `table` when it is known,
+    /// and with the default (Murmur3) partitioner otherwise.
+    /// The `table` is required for tablet-based keyspaces.
+    #[napi(ts_return_type = "Array<import('../lib/host').Host> | null")]
+    pub fn get_replicas_by_routing_key<'env>(
+        &self,
+        env: &'env Env,
+        keyspace: String,
+        table: Option<String>,
+        routing_key: Buffer,
+    ) -> JsResult<Option<Vec<JsInstance<'env, js_constructible_class::Host>>>> {
+        with_custom_error_sync(|| {
+            self.with_cluster_snapshot(env, |cluster_snapshot: &ClusterSnapshot| {
+                let partitioner = table
+                    .as_deref()
+                    .and_then(|table| {
+                        cluster_snapshot
+                            .inner
+                            .get_keyspace(&keyspace)?
+                            .tables
+                            .get(table)?
+                            .partitioner
+                            .as_deref()
+                    })
+                    .filter(|name| name.ends_with("CDCPartitioner"))
+                    .map_or(PartitionerName::Murmur3, |_| PartitionerName::CDC);
+                let token = partitioner.hash_one(&routing_key);
+                cluster_snapshot.replicas(env, &keyspace, table.as_deref(), token)
+            })
+        })
+    }
+
+    /// Returns all ranges of the token ring. Each range is start-exclusive and end-inclusive.
+    #[napi]
+    pub fn get_token_ranges(&self, env: &Env) -> JsResult<Vec<TokenRangeObj>> {
+        with_custom_error_sync(|| {
+            self.with_cluster_snapshot(env, |cluster_snapshot: &ClusterSnapshot| {
+                Ok(ring_ranges(&cluster_snapshot.inner)
+                    .into_iter()
+                    .map(|(start, end)| TokenRangeObj::new(start, end))
+                    .collect())
+            })
+        })
+    }
+
+    /// Returns the token ranges replicated on the host with the given host id, for the given keyspace,
+    /// or `null` if the keyspace is not known.
+    ///
+    /// Tablet-based keyspaces have no token ranges shared by all of their tables,
+    /// so an error is returned for such keyspaces.
+    #[napi]
+    pub fn get_token_ranges_for_host(
+        &self,
+        env: &Env,
+        keyspace: String,
+        host_id: Buffer,
+    ) -> JsResult<Option<Vec<TokenRangeObj>>> {
+        with_custom_error_sync(|| {
+            let host_id = Uuid::from_slice(&host_id).map_err(ConvertedError::from)?;
+            self.with_cluster_snapshot(env, |cluster_snapshot: &ClusterSnapshot| {
+                let cluster_state = &cluster_snapshot.inner;
+                let Some(keyspace_meta) = cluster_state.get_keyspace(&keyspace) else {
+                    return Ok(None);
+                };
+                if keyspace_meta.tablet_based {
+                    return Err(make_js_error(format!(
+                        "Keyspace {keyspace} uses tablets, token ranges are defined per table"
+                    ))
+                    .into());
+                }
+                Ok(Some(
+                    ring_ranges(cluster_state)
+                        .into_iter()
+                        .filter(|(_, end)| {
+                            token_replicas(cluster_state, &keyspace, NO_TABLE, Token::new(*end))
+                                .iter()
+                                .any(|node| node.host_id == host_id)
+                        })
+                        .map(|(start, end)| TokenRangeObj::new(start, end))
+                        .collect(),
+                ))
+            })
+        })
+    }
+}
+
+impl ClusterSnapshot {
+    /// Returns the JS `Host` objects of all replicas of the given token,
+    /// or `None` if the keyspace is not known.
+    fn replicas<'env>(
+        &self,
+        env: &'env Env,
+        keyspace: &str,
+        table: Option<&str>,
+        token: Token,
+    ) -> ConvertedResult<Option<Vec<JsInstance<'env, js_constructible_class::Host>>>> {
+        let Some(keyspace_meta) = self.inner.get_keyspace(keyspace) else {
+            return Ok(None);
+        };
+        let table = resolve_table(keyspace_meta, keyspace, table)?;
+        token_replicas(&self.inner, keyspace, table, token)
+            .iter()
+            // Nodes known by the locator are always present in the snapshot they come from.
+            .filter_map(|node| self.hosts.get(&node.host_id))
+            .map(|host| host.get(env).map_err(ConvertedError::from))
+            .collect::<ConvertedResult<_>>()
+            .map(Some)
+    }
+}
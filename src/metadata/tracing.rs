@@ -0,0 +1,153 @@
+use napi::bindgen_prelude::Buffer;
+use scylla::client::session::Session;
+use scylla::errors::TracingError;
+use scylla::observability::tracing::{TracingEvent, TracingInfo};
+use scylla::statement::{Consistency, Statement};
+use uuid::Uuid;
+
+use crate::errors::{
+    ConvertedError, ConvertedResult, JsResult, make_js_error, with_custom_error_async,
+};
+use crate::session::SessionWrapper;
+use crate::session::config::{TRACE_FETCH_ATTEMPTS, TRACE_FETCH_INTERVAL};
+use crate::utils::to_napi_obj::{NamedMap, define_rust_to_js_convertible_object};
+
+// Shapes of the objects below mirror the columns of the `system_traces` tables.
+// Addresses and ids are passed as strings, and timestamps as milliseconds since the unix epoch.
+// They are converted into the driver types on the JS side.
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct TraceEventObj {
+    event_id, eventId: String,
+    activity, activity: Option<String>,
+    source, source: Option<String>,
+    source_elapsed, sourceElapsed: Option<i32>,
+    thread, thread: Option<String>,
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct TraceObj {
+    client, client: Option<String>,
+    command, command: Option<String>,
+    coordinator, coordinator: Option<String>,
+    duration, duration: Option<i32>,
+    parameters, parameters: NamedMap<String, String>,
+    request, request: Option<String>,
+    started_at, startedAt: Option<i64>,
+    events, events: Vec<TraceEventObj>,
+});
+
+impl From<TracingEvent> for TraceEventObj {
+    fn from(event: TracingEvent) -> Self {
+        TraceEventObj {
+            event_id: event.event_id.to_string(),
+            activity: event.activity,
+            source: event.source.map(|source| source.to_string()),
+            source_elapsed: event.source_elapsed,
+            thread: event.thread,
+        }
+    }
+}
+
+impl From<TracingInfo> for TraceObj {
+    fn from(info: TracingInfo) -> Self {
+        TraceObj {
+            client: info.client.map(|client| client.to_string()),
+            command: info.command,
+            coordinator: info.coordinator.map(|coordinator| coordinator.to_string()),
+            duration: info.duration,
+            parameters: NamedMap::new(info.parameters.unwrap_or_default()),
+            request: info.request,
+            started_at: info.started_at.map(|started_at| started_at.0),
+            events: info.events.into_iter().map(TraceEventObj::from).collect(),
+        }
+    }
+}
+
+const TRACE_SESSION_QUERY: &str = "SELECT client, command, coordinator, duration, parameters, request, started_at \
+    FROM system_traces.sessions WHERE session_id = ?";
+const TRACE_EVENTS_QUERY: &str = "SELECT event_id, activity, source, source_elapsed, thread \
+    FROM system_traces.events WHERE session_id = ?";
+
+/// Reads the trace once, with the given consistency, the same way as `Session::get_tracing_info`.
+/// Returns `None` if the trace is not complete yet: the session has no duration, or no events were recorded.
+async fn try_fetch_trace(
+    session: &Session,
+    trace_id: Uuid,
+    consistency: Consistency,
+) -> ConvertedResult<Option<TracingInfo>> {
+    let mut session_statement = Statement::new(TRACE_SESSION_QUERY);
+    session_statement.set_consistency(consistency);
+    let mut events_statement = Statement::new(TRACE_EVENTS_QUERY);
+    events_statement.set_consistency(consistency);
+
+    let (session_result, events_result) = tokio::try_join!(
+        session.query_unpaged(session_statement, (trace_id,)),
+        session.query_unpaged(events_statement, (trace_id,)),
+    )
+    .map_err(ConvertedError::from_execution_error)?;
+
+    let Some(mut info) = session_result
+        .into_rows_result()?
+        .maybe_first_row::<TracingInfo>()?
+        .filter(|info| info.duration.is_some())
+    else {
+        return Ok(None);
+    };
+    info.events = events_result
+        .into_rows_result()?
+        .rows::<TracingEvent>()?
+        .collect::<Result<_, _>>()?;
+    Ok((!info.events.is_empty()).then_some(info))
+}
+
+/// Reads the trace with the given consistency, as many times and with the same interval
+/// as `Session::get_tracing_info` does with the consistency configured in the session builder.
+async fn fetch_trace(
+    session: &Session,
+    trace_id: Uuid,
+    consistency: Consistency,
+) -> ConvertedResult<TracingInfo> {
+    for attempt in 1..=TRACE_FETCH_ATTEMPTS.get() {
+        if let Some(info) = try_fetch_trace(session, trace_id, consistency).await? {
+            return Ok(info);
+        }
+        if attempt < TRACE_FETCH_ATTEMPTS.get() {
+            tokio::time::sleep(TRACE_FETCH_INTERVAL).await;
+        }
+    }
+    Err(TracingError::EmptyResults.into())
+}
+
+#[napi]
+impl SessionWrapper {
+    /// Reads the trace of a request from the `system_traces` keyspace,
+    /// with the given consistency, or the one configured in the session builder (`ONE`) by default.
+    ///
+    /// As the trace is written asynchronously by the nodes, it is read a few times until it is complete.
+    /// Fails with `TracingError` if the trace is not complete after the last attempt.
+    #[napi(ts_return_type = "Promise<TraceObj>")]
+    pub async fn get_trace(
+        &self,
+        trace_id: Buffer,
+        consistency: Option<u16>,
+    ) -> JsResult<TraceObj> {
+        with_custom_error_async(async || {
+            let trace_id = Uuid::from_slice(&trace_id)?;
+            let session = self.inner.get_session();
+            let info = match consistency {
+                Some(consistency) => {
+                    let consistency = Consistency::try_from(consistency).map_err(|_| {
+                        make_js_error(format!("Unknown consistency value: {consistency}"))
+                    })?;
+                    fetch_trace(session, trace_id, consistency).await?
+                }
+                None => session.get_tracing_info(&trace_id).await?,
+            };
+            ConvertedResult::Ok(TraceObj::from(info))
+        })
+        .await
+    }
+}
@@ -0,0 +1,122 @@
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use scylla::errors::{ExecutionError, RequestAttemptError, RequestError};
+use scylla::observability::history::{AttemptId, HistoryListener, RequestId, SpeculativeId};
+use scylla::policies::retry::RetryDecision;
+
+use crate::errors::ConvertedError;
+use crate::requests::metrics::RequestMetrics;
+
+/// History listener collecting the errors of the attempts of a single request,
+/// by address of the node each attempt was sent to.
+///
+/// The Rust driver returns only the error of the last attempt of a request, while the JS driver
+/// reports the error of each host tried, when no host was able to execute the request.
+///
+/// The attempts are recorded in the metrics of the requests as well.
+#[derive(Debug)]
+pub(crate) struct AttemptErrorsListener {
+    /// Node, start and error of each attempt, indexed by the attempt id.
+    attempts: Mutex<Vec<(SocketAddr, Instant, Option<RequestAttemptError>)>>,
+    /// Number of failed attempts, after which the request was retried.
+    retries: AtomicU32,
+    metrics: Arc<RequestMetrics>,
+}
+
+/// Summary of the attempts of a request, reported to the request tracker.
+#[derive(Default)]
+pub(crate) struct AttemptsSummary {
+    pub(crate) attempts: u32,
+    pub(crate) retries: u32,
+    /// Node of the last attempt, if any attempt was made.
+    pub(crate) last_node: Option<SocketAddr>,
+}
+
+impl AttemptErrorsListener {
+    pub(crate) fn new(metrics: Arc<RequestMetrics>) -> Self {
+        AttemptErrorsListener {
+            attempts: Mutex::new(Vec::new()),
+            retries: AtomicU32::new(0),
+            metrics,
+        }
+    }
+
+    /// Summarizes the attempts made so far.
+    /// Must be called before the error is converted, as the conversion consumes the attempts.
+    pub(crate) fn summary(&self) -> AttemptsSummary {
+        let attempts = self.attempts.lock().unwrap();
+        AttemptsSummary {
+            attempts: attempts.len() as u32,
+            retries: self.retries.load(Ordering::Relaxed),
+            last_node: attempts.last().map(|(node, _, _)| *node),
+        }
+    }
+
+    /// Converts the error of the request, adding the errors of its attempts.
+    /// When the same node was tried multiple times, the error of the last attempt is kept.
+    pub(crate) fn convert_error(&self, error: ExecutionError) -> ConvertedError {
+        let attempts = std::mem::take(&mut *self.attempts.lock().unwrap());
+        ConvertedError::from_execution_error(error).with_attempt_errors(
+            attempts
+                .into_iter()
+                .filter_map(|(node, _, error)| Some((node, error?))),
+        )
+    }
+}
+
+impl HistoryListener for AttemptErrorsListener {
+    fn log_request_start(&self) -> RequestId {
+        // The listener is used for a single request.
+        RequestId(0)
+    }
+
+    fn log_request_success(&self, _request_id: RequestId) {}
+
+    fn log_request_error(&self, _request_id: RequestId, _error: &RequestError) {}
+
+    fn log_new_speculative_fiber(&self, _request_id: RequestId) -> SpeculativeId {
+        self.metrics.speculative_execution();
+        SpeculativeId(0)
+    }
+
+    fn log_attempt_start(
+        &self,
+        _request_id: RequestId,
+        _speculative_id: Option<SpeculativeId>,
+        node_addr: SocketAddr,
+    ) -> AttemptId {
+        let mut attempts = self.attempts.lock().unwrap();
+        attempts.push((node_addr, Instant::now(), None));
+        AttemptId(attempts.len() - 1)
+    }
+
+    fn log_attempt_success(&self, attempt_id: AttemptId) {
+        if let Some((node, start, _)) = self.attempts.lock().unwrap().get(attempt_id.0) {
+            self.metrics.attempt_succeeded(*node, start.elapsed());
+        }
+    }
+
+    fn log_attempt_error(
+        &self,
+        attempt_id: AttemptId,
+        error: &RequestAttemptError,
+        retry_decision: &RetryDecision,
+    ) {
+        if matches!(
+            retry_decision,
+            RetryDecision::RetrySameTarget(_) | RetryDecision::RetryNextTarget(_)
+        ) {
+            self.retries.fetch_add(1, Ordering::Relaxed);
+        }
+        if let Some((node, start, attempt_error)) =
+            self.attempts.lock().unwrap().get_mut(attempt_id.0)
+        {
+            self.metrics
+                .attempt_failed(*node, start.elapsed(), error, retry_decision);
+            *attempt_error = Some(error.clone());
+        }
+    }
+}
@@ -0,0 +1,216 @@
+/// Kind of a token of a CQL statement. Whitespaces and comments are not tokens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TokenKind {
+    /// Unquoted identifier, keyword or number.
+    Word,
+    /// Double-quoted identifier.
+    QuotedName,
+    /// String literal, either quoted or enclosed in `$$`.
+    Literal,
+    /// Any other character, e.g. a dot or a parenthesis.
+    Symbol(u8),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+/// Returns the position right after the first `delimiter` found from `from`,
+/// or the end of the statement, when there is none.
+fn skip_past(statement: &str, from: usize, delimiter: &str) -> usize {
+    statement[from..]
+        .find(delimiter)
+        .map_or(statement.len(), |found| from + found + delimiter.len())
+}
+
+/// Returns the position right after the quoted text starting at `start`,
+/// where the quote character is escaped by doubling it.
+fn skip_quoted(statement: &str, start: usize, quote: u8) -> usize {
+    let bytes = statement.as_bytes();
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        if bytes[pos] == quote {
+            if bytes.get(pos + 1) != Some(&quote) {
+                return pos + 1;
+            }
+            pos += 1;
+        }
+        pos += 1;
+    }
+    bytes.len()
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    // Bytes of multi-byte characters are treated as parts of words,
+    // so that the positions of the tokens are always at character boundaries.
+    byte.is_ascii_alphanumeric() || byte == b'_' || !byte.is_ascii()
+}
+
+fn tokenize(statement: &str) -> Vec<Token> {
+    let bytes = statement.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let rest = &statement[pos..];
+        let kind = if bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        } else if rest.starts_with("--") || rest.starts_with("//") {
+            pos = skip_past(statement, pos, "\n");
+            continue;
+        } else if rest.starts_with("/*") {
+            pos = skip_past(statement, pos + 2, "*/");
+            continue;
+        } else if rest.starts_with("$$") {
+            pos = skip_past(statement, pos + 2, "$$");
+            TokenKind::Literal
+        } else if bytes[pos] == b'\'' {
+            pos = skip_quoted(statement, pos, b'\'');
+            TokenKind::Literal
+        } else if bytes[pos] == b'"' {
+            pos = skip_quoted(statement, pos, b'"');
+            TokenKind::QuotedName
+        } else if is_word_byte(bytes[pos]) {
+            while pos < bytes.len() && is_word_byte(bytes[pos]) {
+                pos += 1;
+            }
+            TokenKind::Word
+        } else {
+            pos += 1;
+            TokenKind::Symbol(bytes[start])
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+    tokens
+}
+
+/// Qualifies the tables of the statement, that are not qualified yet, with the given keyspace.
+///
+/// The keyspace cannot be set per request with the protocol used by the Rust driver,
+/// so the statement itself is changed instead. Each keyspace gets its own statement,
+/// which is also the key of the prepared statements cache.
+///
+/// Tables are qualified in `SELECT`, `INSERT`, `UPDATE`, `DELETE`, `BATCH` and `TRUNCATE` statements,
+/// and in `CREATE TABLE`, `ALTER TABLE` and `DROP TABLE` statements. Other statements are left unchanged.
+pub(crate) fn qualify_tables(statement: &str, keyspace: &str) -> String {
+    let tokens = tokenize(statement);
+    let is_keyword = |index: usize, keyword: &str| {
+        tokens.get(index).is_some_and(|token| {
+            token.kind == TokenKind::Word
+                && statement[token.start..token.end].eq_ignore_ascii_case(keyword)
+        })
+    };
+    let is_table_keyword = |index| is_keyword(index, "TABLE") || is_keyword(index, "COLUMNFAMILY");
+
+    // Positions of the tokens that name the tables of the statement.
+    let mut tables = Vec::new();
+    if ["SELECT", "INSERT", "UPDATE", "DELETE", "BEGIN"]
+        .iter()
+        .any(|keyword| is_keyword(0, keyword))
+    {
+        tables.extend(
+            (0..tokens.len())
+                .filter(|&index| {
+                    ["FROM", "INTO", "UPDATE"]
+                        .iter()
+                        .any(|k| is_keyword(index, k))
+                })
+                .map(|index| index + 1),
+        );
+    } else if is_keyword(0, "TRUNCATE") {
+        tables.push(if is_table_keyword(1) { 2 } else { 1 });
+    } else if ["CREATE", "ALTER", "DROP"]
+        .iter()
+        .any(|keyword| is_keyword(0, keyword))
+        && is_table_keyword(1)
+    {
+        let mut index = 2;
+        if is_keyword(index, "IF") {
+            index += if is_keyword(index + 1, "NOT") { 3 } else { 2 };
+        }
+        tables.push(index);
+    }
+
+    let prefix = format!("\"{}\".", keyspace.replace('"', "\"\""));
+    let mut qualified = String::with_capacity(statement.len() + tables.len() * prefix.len());
+    let mut copied = 0;
+    for index in tables {
+        let is_name = tokens
+            .get(index)
+            .is_some_and(|token| matches!(token.kind, TokenKind::Word | TokenKind::QuotedName));
+        let is_qualified = tokens
+            .get(index + 1)
+            .is_some_and(|token| token.kind == TokenKind::Symbol(b'.'));
+        if is_name && !is_qualified {
+            let start = tokens[index].start;
+            qualified.push_str(&statement[copied..start]);
+            qualified.push_str(&prefix);
+            copied = start;
+        }
+    }
+    qualified.push_str(&statement[copied..]);
+    qualified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_qualified() {
+        assert_eq!(
+            qualify_tables("SELECT a FROM t WHERE b = ?", "ks"),
+            r#"SELECT a FROM "ks".t WHERE b = ?"#
+        );
+        assert_eq!(
+            qualify_tables("insert into \"T\" (a) VALUES ('from x')", "ks"),
+            r#"insert into "ks"."T" (a) VALUES ('from x')"#
+        );
+        assert_eq!(
+            qualify_tables("UPDATE t SET a = 1 WHERE b = 2", "Ks"),
+            r#"UPDATE "Ks".t SET a = 1 WHERE b = 2"#
+        );
+        assert_eq!(
+            qualify_tables(
+                "BEGIN BATCH INSERT INTO t (a) VALUES (1); DELETE FROM u WHERE a = 1; APPLY BATCH",
+                "ks"
+            ),
+            r#"BEGIN BATCH INSERT INTO "ks".t (a) VALUES (1); DELETE FROM "ks".u WHERE a = 1; APPLY BATCH"#
+        );
+        assert_eq!(
+            qualify_tables("TRUNCATE TABLE t", "ks"),
+            r#"TRUNCATE TABLE "ks".t"#
+        );
+        assert_eq!(
+            qualify_tables("CREATE TABLE IF NOT EXISTS t (a int PRIMARY KEY)", "ks"),
+            r#"CREATE TABLE IF NOT EXISTS "ks".t (a int PRIMARY KEY)"#
+        );
+        assert_eq!(
+            qualify_tables("-- FROM x\nSELECT * /* FROM y */ FROM t", "ks"),
+            "-- FROM x\nSELECT * /* FROM y */ FROM \"ks\".t"
+        );
+    }
+
+    #[test]
+    fn qualified_tables_and_other_statements_are_unchanged() {
+        for statement in [
+            "SELECT a FROM other.t",
+            "SELECT a FROM \"Other\" . t",
+            "SELECT a FROM other.fromage WHERE b = 'FROM t'",
+            "CREATE KEYSPACE ks WITH replication = {}",
+            "REVOKE SELECT ON ALL KEYSPACES FROM role",
+            "USE ks",
+            "",
+        ] {
+            assert_eq!(qualify_tables(statement, "ks"), statement);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use histogram::Histogram;
+use scylla::cluster::ClusterState;
+use scylla::errors::{DbError, RequestAttemptError};
+use scylla::observability::metrics::{Metrics, Snapshot};
+use scylla::policies::retry::RetryDecision;
+
+use crate::errors::ConvertedError;
+use crate::utils::js_callback::JsCallback;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+use crate::utils::{Batcher, to_hrtime};
+
+/// Time for which the events are collected, before they are passed to JS together.
+const EVENTS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Configuration of the per-node latency histograms: relative error below 1%, for latencies up to 65 seconds.
+/// Latencies are recorded in milliseconds, like in the metrics of the Rust driver.
+const NODE_HISTOGRAM_GROUPING_POWER: u8 = 7;
+const NODE_HISTOGRAM_MAX_VALUE_POWER: u8 = 16;
+
+// Event measured by the driver, passed to the JS ClientMetrics.
+// Latency is given as [seconds, nanoseconds], like the result of `process.hrtime()`.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub enum MetricsEventObj
+where VariantName: kind {
+    Response {
+        latency, latency: Vec<u32>,
+        success, success: bool,
+    } = "response",
+    Error {
+        error, error: ConvertedError,
+    } = "error",
+    Retry {
+        error, error: ConvertedError,
+    } = "retry",
+    IgnoredError {
+        error, error: ConvertedError,
+    } = "ignoredError",
+    SpeculativeExecution = "speculativeExecution",
+});
+
+// Latency statistics, in milliseconds.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct LatencyObj {
+    min, min: i64,
+    max, max: i64,
+    mean, mean: i64,
+    median, median: i64,
+    percentile_75, percentile75: i64,
+    percentile_95, percentile95: i64,
+    percentile_98, percentile98: i64,
+    percentile_99, percentile99: i64,
+    percentile_99_9, percentile999: i64,
+});
+
+// Metrics of the requests attempted on a single node.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct NodeMetricsObj {
+    host_id, hostId: String,
+    address, address: String,
+    requests, requests: i64,
+    errors, errors: i64,
+    timeouts, timeouts: i64,
+    retries, retries: i64,
+    latency, latency: Option<LatencyObj>,
+});
+
+// Metrics of the session. Latency is not known until the first request finishes.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct MetricsObj {
+    requests, requests: i64,
+    errors, errors: i64,
+    retries, retries: i64,
+    request_timeouts, requestTimeouts: i64,
+    connections, connections: i64,
+    connection_timeouts, connectionTimeouts: i64,
+    mean_rate, meanRate: f64,
+    one_minute_rate, oneMinuteRate: f64,
+    five_minute_rate, fiveMinuteRate: f64,
+    fifteen_minute_rate, fifteenMinuteRate: f64,
+    latency, latency: Option<LatencyObj>,
+    nodes, nodes: Vec<NodeMetricsObj>,
+});
+
+impl From<Snapshot> for LatencyObj {
+    fn from(snapshot: Snapshot) -> Self {
+        LatencyObj {
+            min: snapshot.min as i64,
+            max: snapshot.max as i64,
+            mean: snapshot.mean as i64,
+            median: snapshot.median as i64,
+            percentile_75: snapshot.percentile_75 as i64,
+            percentile_95: snapshot.percentile_95 as i64,
+            percentile_98: snapshot.percentile_98 as i64,
+            percentile_99: snapshot.percentile_99 as i64,
+            percentile_99_9: snapshot.percentile_99_9 as i64,
+        }
+    }
+}
+
+impl LatencyObj {
+    /// Computes the statistics of the histogram, counting each value as the middle of its bucket,
+    /// as the Rust driver does. Returns `None` for an empty histogram.
+    fn from_histogram(histogram: &Histogram) -> Option<Self> {
+        let buckets = || histogram.into_iter().filter(|bucket| bucket.count() > 0);
+        let middle = |start: u64, end: u64| (start + end) / 2;
+        let count: u64 = buckets().map(|bucket| bucket.count()).sum();
+        let sum: u128 = buckets()
+            .map(|bucket| middle(bucket.start(), bucket.end()) as u128 * bucket.count() as u128)
+            .sum();
+        let mean = sum.checked_div(count as u128)? as u64;
+        let quantiles = histogram
+            .quantiles(&[0.5, 0.75, 0.95, 0.98, 0.99, 0.999])
+            .ok()??;
+        let mut quantiles = quantiles
+            .entries()
+            .values()
+            .map(|bucket| middle(bucket.start(), bucket.end()) as i64);
+        let mut quantile = || quantiles.next().unwrap_or_default();
+        Some(LatencyObj {
+            min: buckets().next().map_or(0, |bucket| bucket.start()) as i64,
+            max: buckets().last().map_or(0, |bucket| bucket.end()) as i64,
+            mean: mean as i64,
+            median: quantile(),
+            percentile_75: quantile(),
+            percentile_95: quantile(),
+            percentile_98: quantile(),
+            percentile_99: quantile(),
+            percentile_99_9: quantile(),
+        })
+    }
+}
+
+/// JS function receiving the events measured by the driver, passed with the session options.
+pub type MetricsEventsCallback = JsCallback<Vec<MetricsEventObj>, ()>;
+
+/// Counters and latency histogram of the requests attempted on a single node.
+struct NodeStats {
+    requests: u64,
+    errors: u64,
+    timeouts: u64,
+    retries: u64,
+    latency: Histogram,
+}
+
+impl NodeStats {
+    fn new() -> Self {
+        NodeStats {
+            requests: 0,
+            errors: 0,
+            timeouts: 0,
+            retries: 0,
+            latency: Histogram::new(
+                NODE_HISTOGRAM_GROUPING_POWER,
+                NODE_HISTOGRAM_MAX_VALUE_POWER,
+            )
+            .expect("Histogram configuration should be valid"),
+        }
+    }
+}
+
+/// Metrics of the requests, measured on top of the metrics of the Rust driver.
+///
+/// Collects the metrics of each node, and passes the events measured by the driver
+/// to the JS ClientMetrics, when configured in the session options.
+pub(crate) struct RequestMetrics {
+    nodes: Mutex<HashMap<SocketAddr, NodeStats>>,
+    events: Option<(MetricsEventsCallback, Batcher<MetricsEventObj>)>,
+}
+
+impl fmt::Debug for RequestMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestMetrics")
+    }
+}
+
+impl RequestMetrics {
+    pub(crate) fn new(callback: Option<MetricsEventsCallback>) -> Self {
+        RequestMetrics {
+            nodes: Mutex::new(HashMap::new()),
+            events: callback.map(|callback| (callback, Batcher::new(EVENTS_INTERVAL))),
+        }
+    }
+
+    /// Passes the event to JS. The event is created only when the events are passed to JS.
+    fn emit(&self, event: impl FnOnce() -> MetricsEventObj) {
+        let Some((callback, pending)) = &self.events else {
+            return;
+        };
+        let callback = callback.clone();
+        pending.push(event(), move |events| {
+            callback.call(events);
+        });
+    }
+
+    fn record_attempt(
+        &self,
+        node: SocketAddr,
+        latency: Duration,
+        record: impl FnOnce(&mut NodeStats),
+    ) {
+        let mut nodes = self.nodes.lock().unwrap();
+        let stats = nodes.entry(node).or_insert_with(NodeStats::new);
+        stats.requests += 1;
+        // Latencies above the maximum of the histogram are counted as the maximum.
+        let max_latency = (1 << NODE_HISTOGRAM_MAX_VALUE_POWER) - 1;
+        let _ = stats
+            .latency
+            .increment((latency.as_millis() as u64).min(max_latency));
+        record(stats);
+    }
+
+    pub(crate) fn attempt_succeeded(&self, node: SocketAddr, latency: Duration) {
+        self.record_attempt(node, latency, |_| ());
+        self.emit(|| MetricsEventObj::Response {
+            latency: to_hrtime(latency),
+            success: true,
+        });
+    }
+
+    pub(crate) fn attempt_failed(
+        &self,
+        node: SocketAddr,
+        latency: Duration,
+        error: &RequestAttemptError,
+        retry_decision: &RetryDecision,
+    ) {
+        let is_retried = matches!(
+            retry_decision,
+            RetryDecision::RetrySameTarget(_) | RetryDecision::RetryNextTarget(_)
+        );
+        self.record_attempt(node, latency, |stats| {
+            stats.errors += 1;
+            if matches!(
+                error,
+                RequestAttemptError::DbError(
+                    DbError::ReadTimeout { .. } | DbError::WriteTimeout { .. },
+                    _
+                )
+            ) {
+                stats.timeouts += 1;
+            }
+            if is_retried {
+                stats.retries += 1;
+            }
+        });
+        // Errors returned by the database are responses as well.
+        if let RequestAttemptError::DbError(..) = error {
+            self.emit(|| MetricsEventObj::Response {
+                latency: to_hrtime(latency),
+                success: false,
+            });
+        }
+        let error = || ConvertedError::from_attempt_error(error.clone());
+        match retry_decision {
+            _ if is_retried => self.emit(|| MetricsEventObj::Retry { error: error() }),
+            RetryDecision::IgnoreWriteError => {
+                self.emit(|| MetricsEventObj::IgnoredError { error: error() })
+            }
+            _ => (),
+        }
+    }
+
+    pub(crate) fn speculative_execution(&self) {
+        self.emit(|| MetricsEventObj::SpeculativeExecution);
+    }
+
+    pub(crate) fn request_failed(&self, error: &ConvertedError) {
+        self.emit(|| MetricsEventObj::Error {
+            error: error.clone(),
+        });
+    }
+
+    /// Returns the metrics of the Rust driver, with the metrics of each node of the cluster.
+    /// Nodes that are no longer part of the cluster are not included.
+    pub(crate) fn snapshot(&self, metrics: &Metrics, cluster: &ClusterState) -> MetricsObj {
+        let nodes = self.nodes.lock().unwrap();
+        let nodes = cluster
+            .get_nodes_info()
+            .iter()
+            .filter_map(|node| {
+                let address = SocketAddr::new(node.address.ip(), node.address.port());
+                let stats = nodes.get(&address)?;
+                Some(NodeMetricsObj {
+                    host_id: node.host_id.to_string(),
+                    address: address.to_string(),
+                    requests: stats.requests as i64,
+                    errors: stats.errors as i64,
+                    timeouts: stats.timeouts as i64,
+                    retries: stats.retries as i64,
+                    latency: LatencyObj::from_histogram(&stats.latency),
+                })
+            })
+            .collect();
+        MetricsObj {
+            requests: (metrics.get_requests_unpaged_num()
+                + metrics.get_requests_manually_paged_num()) as i64,
+            errors: (metrics.get_errors_unpaged_num() + metrics.get_errors_manually_paged_num())
+                as i64,
+            retries: metrics.get_retries_num() as i64,
+            request_timeouts: metrics.get_request_timeouts() as i64,
+            connections: metrics.get_total_connections() as i64,
+            connection_timeouts: metrics.get_connection_timeouts() as i64,
+            mean_rate: metrics.get_mean_rate(),
+            one_minute_rate: metrics.get_one_minute_rate(),
+            five_minute_rate: metrics.get_five_minute_rate(),
+            fifteen_minute_rate: metrics.get_fifteen_minute_rate(),
+            latency: metrics.get_snapshot().ok().map(LatencyObj::from),
+            nodes,
+        }
+    }
+}
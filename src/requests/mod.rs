@@ -1 +1,6 @@
+pub mod history;
+pub mod keyspace;
+pub mod metrics;
 pub mod request;
+pub mod routing;
+pub mod tracker;
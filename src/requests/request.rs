@@ -2,7 +2,8 @@ use napi::bindgen_prelude::BigInt;
 use scylla::statement::prepared::PreparedStatement;
 
 use crate::{
-    types::type_wrappers::ComplexType, utils::from_napi_obj::define_js_to_rust_convertible_object,
+    types::encoded_data::EncodedValuesWrapper, types::type_wrappers::ComplexType,
+    utils::from_napi_obj::define_js_to_rust_convertible_object,
 };
 
 pub(crate) struct PreparedStatementWrapper {
@@ -11,18 +12,17 @@ pub(crate) struct PreparedStatementWrapper {
 
 // Missing fields
 // customPayload?, any;
-// executionProfile?, string | ExecutionProfile;
 // hints?, string[] | string[][];
 // host?, Host;
 // pageState?, Buffer | string;
 // retry?, policies.retry.RetryPolicy;
-// routingKey?, Buffer | Buffer[];
 define_js_to_rust_convertible_object!(
 pub struct QueryOptionsObj{
     auto_page, autoPage: bool,
     capture_stack_trace, captureStackTrace: bool,
     consistency, consistency: u16,
     counter, counter: bool,
+    execution_profile, executionProfile: String,
     fetch_size, fetchSize: i32,
     is_idempotent, isIdempotent: bool,
     keyspace, keyspace: String,
@@ -30,10 +30,12 @@ pub struct QueryOptionsObj{
     prepare, prepare: bool,
     read_timeout, readTimeout: i32,
     routing_indexes, routingIndexes: Vec<i32>,
+    routing_key, routingKey: Vec<EncodedValuesWrapper>,
     routing_names, routingNames: Vec<String>,
     serial_consistency, serialConsistency: i16,
     timestamp, timestamp: BigInt,
     trace_query, traceQuery: bool,
+    wait_for_schema_agreement, waitForSchemaAgreement: bool,
 }
 );
 
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use scylla::client::execution_profile::ExecutionProfileHandle;
+use scylla::cluster::{ClusterState, NodeRef};
+use scylla::errors::RequestAttemptError;
+use scylla::frame::response::result::TableSpec;
+use scylla::policies::load_balancing::{FallbackPlan, LoadBalancingPolicy, RoutingInfo};
+use scylla::routing::partitioner::{Partitioner, PartitionerName};
+use scylla::routing::{Shard, Token};
+use scylla::statement::Statement;
+
+use crate::errors::{ConvertedError, RoutingNamesError};
+use crate::requests::request::QueryOptionsObj;
+use crate::session::SessionWrapper;
+use crate::types::encoded_data::EncodedValuesWrapper;
+
+/// Load balancing policy that routes an unprepared request with the token of a routing key.
+///
+/// The Rust driver computes tokens for prepared statements only, as it needs the partition key
+/// metadata returned by the server when preparing. For other requests, the routing key is provided
+/// by the user (or computed from the routing indexes), so this policy fills the routing information
+/// with the token of that key, and delegates all decisions to the policy it wraps.
+#[derive(Debug)]
+pub(crate) struct RoutingKeyPolicy {
+    inner: Arc<dyn LoadBalancingPolicy>,
+    token: Token,
+    // The table of the request is not known, so the token is routed using
+    // the replication strategy of the keyspace over the token ring.
+    // Tables of tablet-based keyspaces distribute their data per table, so requests
+    // to such tables are not routed to the replicas of the token, without the name of the table.
+    table: TableSpec<'static>,
+}
+
+impl RoutingKeyPolicy {
+    fn routing_info<'a>(&'a self, request: &RoutingInfo<'a>) -> RoutingInfo<'a> {
+        let mut routing_info = request.clone();
+        routing_info.token = Some(self.token);
+        routing_info.table = Some(&self.table);
+        routing_info
+    }
+}
+
+// Plans returned by the wrapped policy borrow the routing information created for that call,
+// so nodes are looked up again in the cluster state, to get references that live long enough.
+fn from_cluster<'a>(
+    cluster: &'a ClusterState,
+    (node, shard): (NodeRef<'_>, Option<Shard>),
+) -> Option<(NodeRef<'a>, Option<Shard>)> {
+    cluster
+        .get_node_by_host_id(node.host_id)
+        .map(|node| (node, shard))
+}
+
+impl LoadBalancingPolicy for RoutingKeyPolicy {
+    fn pick<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        let routing_info = self.routing_info(request);
+        self.inner
+            .pick(&routing_info, cluster)
+            .and_then(|target| from_cluster(cluster, target))
+    }
+
+    fn fallback<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> FallbackPlan<'a> {
+        let routing_info = self.routing_info(request);
+        let plan: Vec<_> = self
+            .inner
+            .fallback(&routing_info, cluster)
+            .filter_map(|target| from_cluster(cluster, target))
+            .collect();
+        Box::new(plan.into_iter())
+    }
+
+    fn on_request_success(&self, request: &RoutingInfo, latency: Duration, node: NodeRef<'_>) {
+        self.inner
+            .on_request_success(&self.routing_info(request), latency, node);
+    }
+
+    fn on_request_failure(
+        &self,
+        request: &RoutingInfo,
+        latency: Duration,
+        node: NodeRef<'_>,
+        error: &RequestAttemptError,
+    ) {
+        self.inner
+            .on_request_failure(&self.routing_info(request), latency, node, error);
+    }
+
+    fn name(&self) -> String {
+        format!("RoutingKeyPolicy({})", self.inner.name())
+    }
+}
+
+/// Serializes the routing key from its components, the same way as the partition key is
+/// serialized by the database: a single component is used as is, and each component
+/// of a composite key is prefixed with its length and followed by a zero byte.
+fn compose_routing_key(parts: &[&[u8]]) -> Option<Vec<u8>> {
+    match parts {
+        [] => None,
+        [part] => Some(part.to_vec()),
+        parts => {
+            let mut key = Vec::with_capacity(parts.iter().map(|part| part.len() + 3).sum());
+            for part in parts {
+                key.extend_from_slice(&u16::try_from(part.len()).ok()?.to_be_bytes());
+                key.extend_from_slice(part);
+                key.push(0);
+            }
+            Some(key)
+        }
+    }
+}
+
+/// Options of a request that are used to route it to the replicas of its partition.
+pub(crate) struct RoutingOptions {
+    routing_key: Option<Vec<u8>>,
+    routing_indexes: Option<Vec<i32>>,
+    keyspace: Option<String>,
+}
+
+/// Routing options of an unprepared request.
+///
+/// The `routingNames` option is rejected: unprepared statements cannot have named parameters,
+/// so there are no names to look the values of the routing key up by.
+impl TryFrom<&QueryOptionsObj> for RoutingOptions {
+    type Error = ConvertedError;
+
+    fn try_from(options: &QueryOptionsObj) -> Result<Self, ConvertedError> {
+        if options
+            .routing_names
+            .as_ref()
+            .is_some_and(|names| !names.is_empty())
+        {
+            return Err(ConvertedError::argument(RoutingNamesError));
+        }
+        Ok(RoutingOptions {
+            // Like with routing indexes, a key with a null or unset component is ignored.
+            routing_key: options.routing_key.as_ref().and_then(|parts| {
+                let parts = parts
+                    .iter()
+                    .map(EncodedValuesWrapper::as_value)
+                    .collect::<Option<Vec<&[u8]>>>()?;
+                compose_routing_key(&parts)
+            }),
+            routing_indexes: options.routing_indexes.clone(),
+            keyspace: options.keyspace.clone(),
+        })
+    }
+}
+
+impl RoutingOptions {
+    /// Returns the token of the routing key of the request: the `routingKey` option if provided,
+    /// or the key built from the parameters at `routingIndexes` otherwise.
+    ///
+    /// When the key cannot be built (index out of bounds, null or unset value),
+    /// `None` is returned, and the request is routed without a token.
+    /// An invalid value will make the request itself fail later.
+    ///
+    /// Unprepared requests carry no information about the partitioner of the table,
+    /// so the default one is used. Neither do they carry the name of the table, so requests to tables
+    /// of tablet-based keyspaces are routed without the token (see `RoutingKeyPolicy`).
+    pub(crate) fn token(&self, params: &[EncodedValuesWrapper]) -> Option<Token> {
+        if let Some(key) = &self.routing_key {
+            return Some(PartitionerName::Murmur3.hash_one(key));
+        }
+        let parts = self
+            .routing_indexes
+            .as_ref()?
+            .iter()
+            .map(|index| params.get(usize::try_from(*index).ok()?)?.as_value())
+            .collect::<Option<Vec<&[u8]>>>()?;
+        compose_routing_key(&parts).map(|key| PartitionerName::Murmur3.hash_one(&key))
+    }
+}
+
+impl SessionWrapper {
+    /// Returns the load balancing policy routing an unprepared request to the replicas
+    /// of its routing key, or `None` if the routing key or keyspace of the request are not known.
+    ///
+    /// The returned policy wraps the policy that would be used for the request otherwise:
+    /// the policy of the statement, of its execution profile or of the default execution profile.
+    pub(crate) fn routing_key_policy(
+        &self,
+        routing: &RoutingOptions,
+        params: &[EncodedValuesWrapper],
+        statement_policy: Option<&Arc<dyn LoadBalancingPolicy>>,
+        statement_profile: Option<&ExecutionProfileHandle>,
+    ) -> Option<Arc<dyn LoadBalancingPolicy>> {
+        let token = routing.token(params)?;
+        let keyspace = routing.keyspace.clone().or_else(|| self.get_keyspace())?;
+        let inner = match statement_policy {
+            Some(policy) => policy.clone(),
+            None => statement_profile
+                .unwrap_or(
+                    self.inner
+                        .get_session()
+                        .get_default_execution_profile_handle(),
+                )
+                .to_profile()
+                .get_load_balancing_policy()
+                .clone(),
+        };
+        Some(Arc::new(RoutingKeyPolicy {
+            inner,
+            token,
+            table: TableSpec::owned(keyspace, String::new()),
+        }))
+    }
+
+    /// Routes the unprepared statement to the replicas of its routing key, if it is known.
+    pub(crate) fn apply_routing_key(
+        &self,
+        statement: &mut Statement,
+        routing: &RoutingOptions,
+        params: &[EncodedValuesWrapper],
+    ) {
+        if let Some(policy) = self.routing_key_policy(
+            routing,
+            params,
+            statement.get_load_balancing_policy(),
+            statement.get_execution_profile_handle(),
+        ) {
+            statement.set_load_balancing_policy(Some(policy));
+        }
+    }
+}
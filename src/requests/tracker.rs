@@ -0,0 +1,167 @@
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use scylla::response::query_result::QueryResult;
+use scylla::statement::batch::{Batch, BatchStatement, BatchType};
+
+use crate::errors::ConvertedError;
+use crate::requests::history::AttemptsSummary;
+use crate::types::encoded_data::EncodedValuesWrapper;
+use crate::utils::js_callback::JsCallback;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+use crate::utils::{Batcher, to_hrtime};
+
+/// Time for which the reports are collected, before they are passed to JS together.
+const REPORT_INTERVAL: Duration = Duration::from_millis(100);
+
+// Finished request, reported to the JS request tracker.
+// Latency is given as [seconds, nanoseconds], like the result of `process.hrtime()`.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct RequestReportObj {
+    queries, queries: Vec<String>,
+    is_batch, isBatch: bool,
+    is_batch_logged, isBatchLogged: bool,
+    is_prepared, isPrepared: bool,
+    params_count, paramsCount: u32,
+    request_length, requestLength: u32,
+    response_length, responseLength: Option<u32>,
+    coordinator, coordinator: Option<String>,
+    attempts, attempts: u32,
+    retries, retries: u32,
+    latency, latency: Vec<u32>,
+    error, error: Option<ConvertedError>,
+});
+
+/// JS function receiving the reports of the finished requests, passed with the session options.
+pub type RequestTrackerCallback = JsCallback<Vec<RequestReportObj>, ()>;
+
+/// Request executed while a request tracker is configured, reported once it finishes.
+pub(crate) struct TrackedRequest {
+    queries: Vec<String>,
+    is_batch: bool,
+    is_batch_logged: bool,
+    is_prepared: bool,
+    params_count: u32,
+    /// Estimated from the size of the queries and of the serialized values.
+    request_length: u32,
+    start: Instant,
+}
+
+impl TrackedRequest {
+    pub(crate) fn statement(
+        query: &str,
+        params: &[EncodedValuesWrapper],
+        is_prepared: bool,
+    ) -> Self {
+        TrackedRequest {
+            queries: vec![query.to_owned()],
+            is_batch: false,
+            is_batch_logged: false,
+            is_prepared,
+            params_count: params.len() as u32,
+            request_length: (query.len() + values_size(params)) as u32,
+            start: Instant::now(),
+        }
+    }
+
+    pub(crate) fn batch(batch: &Batch, params: &[Vec<EncodedValuesWrapper>]) -> Self {
+        let queries: Vec<String> = batch
+            .statements
+            .iter()
+            .map(|statement| match statement {
+                BatchStatement::Query(statement) => statement.contents.clone(),
+                BatchStatement::PreparedStatement(prepared) => prepared.get_statement().to_owned(),
+                // Kinds of statements added in the future are reported without their text.
+                _ => String::new(),
+            })
+            .collect();
+        TrackedRequest {
+            request_length: (queries.iter().map(String::len).sum::<usize>()
+                + params
+                    .iter()
+                    .map(|values| values_size(values))
+                    .sum::<usize>()) as u32,
+            is_batch: true,
+            is_batch_logged: batch.get_type() == BatchType::Logged,
+            is_prepared: batch
+                .statements
+                .iter()
+                .all(|statement| matches!(statement, BatchStatement::PreparedStatement(_))),
+            params_count: params.iter().map(Vec::len).sum::<usize>() as u32,
+            queries,
+            start: Instant::now(),
+        }
+    }
+
+    /// Creates the report of the request, with the result, or the error of the request.
+    pub(crate) fn finish(
+        self,
+        summary: AttemptsSummary,
+        outcome: Result<&QueryResult, ConvertedError>,
+    ) -> RequestReportObj {
+        let latency = self.start.elapsed();
+        let (coordinator, response_length, error) = match outcome {
+            Ok(result) => (
+                Some({
+                    let address = result.request_coordinator().node().address;
+                    SocketAddr::new(address.ip(), address.port())
+                }),
+                Some(
+                    result
+                        .clone()
+                        .into_rows_result()
+                        .map_or(0, |rows| rows.rows_bytes_size()) as u32,
+                ),
+                None,
+            ),
+            Err(error) => (summary.last_node, None, Some(error)),
+        };
+        RequestReportObj {
+            queries: self.queries,
+            is_batch: self.is_batch,
+            is_batch_logged: self.is_batch_logged,
+            is_prepared: self.is_prepared,
+            params_count: self.params_count,
+            request_length: self.request_length,
+            response_length,
+            coordinator: coordinator.map(|node| node.to_string()),
+            attempts: summary.attempts,
+            retries: summary.retries,
+            latency: to_hrtime(latency),
+            error,
+        }
+    }
+}
+
+fn values_size(values: &[EncodedValuesWrapper]) -> usize {
+    values
+        .iter()
+        .map(EncodedValuesWrapper::serialized_size)
+        .sum()
+}
+
+/// Reports the finished requests to the JS request tracker.
+///
+/// To limit the number of calls into JS, the reports are collected for `REPORT_INTERVAL`,
+/// and passed to JS together.
+pub(crate) struct RequestTracker {
+    callback: RequestTrackerCallback,
+    pending: Batcher<RequestReportObj>,
+}
+
+impl RequestTracker {
+    pub(crate) fn new(callback: RequestTrackerCallback) -> Self {
+        RequestTracker {
+            callback,
+            pending: Batcher::new(REPORT_INTERVAL),
+        }
+    }
+
+    pub(crate) fn report(&self, report: RequestReportObj) {
+        let callback = self.callback.clone();
+        self.pending.push(report, move |reports| {
+            callback.call(reports);
+        });
+    }
+}
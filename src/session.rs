@@ -1,25 +1,42 @@
+pub mod authentication;
 pub mod config;
+pub mod load_balancing;
+pub mod retry;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use config::SessionOptions;
 use napi::Env;
 use scylla::client::caching_session::CachingSession;
+use scylla::client::execution_profile::ExecutionProfileHandle;
+use scylla::errors::ExecutionError;
+use scylla::response::query_result::QueryResult;
 use scylla::response::{PagingState, PagingStateResponse};
-use scylla::statement::batch::Batch;
+use scylla::statement::batch::{Batch, BatchStatement, BatchType};
 use scylla::statement::{Consistency, SerialConsistency, Statement};
 
 use crate::errors::{
-    ConvertedError, ConvertedResult, JsResult, make_js_error, with_custom_error_async,
-    with_custom_error_sync,
+    ConvertedError, ConvertedResult, InvalidBatchTypeError, JsResult, make_js_error,
+    with_custom_error_async, with_custom_error_sync,
 };
+use crate::metadata::events::watch_cluster;
+use crate::metadata::host::ReleaseVersions;
 use crate::metadata::state::ClusterSnapshot;
 use crate::paging::{PagingResult, PagingResultWithExecutor, PagingStateWrapper};
+use crate::requests::history::{AttemptErrorsListener, AttemptsSummary};
+use crate::requests::keyspace::qualify_tables;
+use crate::requests::metrics::{MetricsObj, RequestMetrics};
 use crate::requests::request::{QueryOptionsObj, QueryOptionsWrapper};
-use crate::session::config::configure_session_builder;
+use crate::requests::routing::RoutingOptions;
+use crate::requests::tracker::{RequestTracker, TrackedRequest};
+use crate::session::config::{
+    DEFAULT_PROFILE_NAME, configure_session_builder, create_execution_profiles,
+};
 use crate::types::encoded_data::EncodedValuesWrapper;
 use crate::types::type_wrappers::ComplexType;
-use crate::utils::bigint_to_i64;
 use crate::utils::js_thread_only::JsThreadOnly;
+use crate::utils::{bigint_to_i64, timeout_from_millis};
 use crate::{requests::request::PreparedStatementWrapper, result::QueryResultWrapper};
 
 const DEFAULT_CACHE_SIZE: u32 = 512;
@@ -27,14 +44,34 @@ const DEFAULT_CACHE_SIZE: u32 = 512;
 #[napi]
 pub struct BatchWrapper {
     inner: Batch,
+    routing: RoutingOptions,
 }
 
 #[napi]
 pub struct SessionWrapper {
-    pub(crate) inner: CachingSession,
+    pub(crate) inner: Arc<CachingSession>,
     /// Cache of the last `ClusterSnapshot` that was computed, alongside the `Arc<ClusterState>`
     /// pointer it was built from.
     cluster_snapshot: Mutex<Option<JsThreadOnly<ClusterSnapshot>>>,
+    /// Release versions of the nodes, read when requested by the JS `Host` objects.
+    release_versions: Arc<ReleaseVersions>,
+    /// Schema agreement settings of the client. The Rust driver only waits for schema agreement
+    /// after schema changes when the session is built, so the main session never waits
+    /// by itself, and the wait is done per request instead.
+    schema_agreement: SchemaAgreementSettings,
+    /// Tracker reporting the finished requests to JS, when configured in the session options.
+    request_tracker: Option<RequestTracker>,
+    /// Metrics of the requests, on top of the metrics of the Rust driver.
+    request_metrics: Arc<RequestMetrics>,
+    /// Execution profiles declared in the session options, by name, apart from the default one.
+    execution_profiles: HashMap<String, ExecutionProfileHandle>,
+}
+
+/// Whether, and how, to wait for schema agreement after requests changing the schema.
+struct SchemaAgreementSettings {
+    /// Disabled with a schema agreement timeout of 0 in the client options.
+    await_schema_agreement: bool,
+    refresh_metadata: bool,
 }
 
 /// This object allows executing queries for following pages of the result,
@@ -45,6 +82,9 @@ pub struct QueryExecutor {
     params: Arc<Vec<EncodedValuesWrapper>>,
     statement: Arc<Statement>,
     is_prepared: bool,
+    /// Whether to wait for schema agreement after the statement changes the schema.
+    /// Disabled with the `waitForSchemaAgreement` option.
+    await_schema_agreement: bool,
 }
 
 impl QueryExecutor {
@@ -52,11 +92,13 @@ impl QueryExecutor {
         statement: Arc<Statement>,
         params: Arc<Vec<EncodedValuesWrapper>>,
         is_prepared: bool,
+        await_schema_agreement: bool,
     ) -> Self {
         QueryExecutor {
             statement,
             params,
             is_prepared,
+            await_schema_agreement,
         }
     }
 }
@@ -71,26 +113,33 @@ impl QueryExecutor {
             .map(|e| e.inner.clone())
             .unwrap_or(PagingState::start());
 
-        let (result, paging_state_response) = if self.is_prepared {
+        let request = session.track_request(|| {
+            TrackedRequest::statement(&self.statement.contents, &self.params, self.is_prepared)
+        });
+        let attempts = Arc::new(AttemptErrorsListener::new(session.request_metrics.clone()));
+        let mut statement = Statement::clone(self.statement.as_ref());
+        statement.set_history_listener(attempts.clone());
+        let result = if self.is_prepared {
             session
                 .inner
-                .execute_single_page(
-                    Statement::clone(self.statement.as_ref()),
-                    self.params.as_ref(),
-                    paging_state,
-                )
+                .execute_single_page(statement, self.params.as_ref(), paging_state)
                 .await
         } else {
             session
                 .inner
                 .get_session()
-                .query_single_page(
-                    Statement::clone(self.statement.as_ref()),
-                    self.params.as_ref(),
-                    paging_state,
-                )
+                .query_single_page(statement, self.params.as_ref(), paging_state)
                 .await
-        }?;
+        };
+        let result = session
+            .await_schema_agreement_after(
+                &self.statement.contents,
+                self.await_schema_agreement,
+                result,
+            )
+            .await;
+        let (result, paging_state_response) =
+            session.finish_request(request, &attempts, result, |(result, _)| result)?;
 
         Ok(PagingResult {
             result: QueryResultWrapper::from_query(result)?,
@@ -123,12 +172,29 @@ impl SessionWrapper {
     pub async fn create_session(options: SessionOptions) -> JsResult<SessionWrapper> {
         with_custom_error_async(async || {
             let cache_size = options.cache_size.unwrap_or(DEFAULT_CACHE_SIZE) as usize;
+            let request_tracker = options.request_tracker.clone().map(RequestTracker::new);
+            let request_metrics = Arc::new(RequestMetrics::new(options.metrics_events.clone()));
+            let cluster_events = options.cluster_events.clone();
+            let execution_profiles = create_execution_profiles(&options)?;
             let builder = configure_session_builder(options)?;
-            let session = builder.build().await?;
-            let session: CachingSession = CachingSession::from(session, cache_size);
+            let schema_agreement = SchemaAgreementSettings {
+                await_schema_agreement: builder.config.schema_agreement_automatic_waiting,
+                refresh_metadata: builder.config.refresh_metadata_on_auto_schema_agreement,
+            };
+            let session = builder.auto_await_schema_agreement(false).build().await?;
+            let session = Arc::new(CachingSession::from(session, cache_size));
+            let release_versions = Arc::new(ReleaseVersions::new(Arc::downgrade(&session)));
+            if let Some(callback) = cluster_events {
+                tokio::spawn(watch_cluster(Arc::downgrade(&session), callback));
+            }
             ConvertedResult::Ok(SessionWrapper {
                 inner: session,
                 cluster_snapshot: Mutex::new(None),
+                release_versions,
+                schema_agreement,
+                request_tracker,
+                request_metrics,
+                execution_profiles,
             })
         })
         .await
@@ -144,6 +210,17 @@ impl SessionWrapper {
             .map(ToOwned::to_owned)
     }
 
+    /// Returns the metrics of the Rust driver, with the metrics of each node of the cluster.
+    ///
+    /// Metrics of the Rust driver cover the requests executed with the settings of the session.
+    /// Metrics of the nodes cover all the requests.
+    #[napi(getter)]
+    pub fn metrics(&self) -> MetricsObj {
+        let session = self.inner.get_session();
+        self.request_metrics
+            .snapshot(&session.get_metrics(), &session.get_cluster_state())
+    }
+
     /// Executes unprepared statement. This assumes the types will be either guessed or provided by user.
     ///
     /// Returns a wrapper of the result provided by the rust driver
@@ -159,13 +236,34 @@ impl SessionWrapper {
         options: &QueryOptionsWrapper,
     ) -> JsResult<QueryResultWrapper> {
         with_custom_error_async(async || {
-            let statement: Statement =
-                self.apply_statement_options(query.into(), &options.options)?;
-            let query_result = self
+            let mut request =
+                self.track_request(|| TrackedRequest::statement(&query, &params, false));
+            let (mut statement, routing) = RoutingOptions::try_from(&options.options)
+                .and_then(|routing| {
+                    let statement = self.apply_statement_options(
+                        statement_in_keyspace(query, &options.options).into(),
+                        &options.options,
+                    )?;
+                    Ok((statement, routing))
+                })
+                .map_err(|error| self.fail_request(request.take(), error))?;
+            self.apply_routing_key(&mut statement, &routing, &params);
+            let attempts = Arc::new(AttemptErrorsListener::new(self.request_metrics.clone()));
+            statement.set_history_listener(attempts.clone());
+            let contents = statement.contents.clone();
+            let result = self
                 .inner
                 .get_session()
                 .query_unpaged(statement, params)
-                .await?;
+                .await;
+            let result = self
+                .await_schema_agreement_after(
+                    &contents,
+                    await_schema_agreement(&options.options),
+                    result,
+                )
+                .await;
+            let query_result = self.finish_request(request, &attempts, result, |result| result)?;
             QueryResultWrapper::from_query(query_result)
         })
         .await
@@ -173,18 +271,27 @@ impl SessionWrapper {
 
     /// Prepares a statement through rust driver for a given session.
     /// Returns (expected type, variable name) pairs for the prepared statement.
+    ///
+    /// When the keyspace is provided, the tables of the statement are qualified with that keyspace,
+    /// the same way as when the statement is executed with the `keyspace` option.
     #[napi(ts_return_type = "Promise<Array<[ComplexType, string]>>")]
     pub async fn prepare_statement(
         &self,
         statement: String,
+        keyspace: Option<String>,
     ) -> JsResult<Vec<(ComplexType<'static>, String)>> {
         with_custom_error_async(async || {
-            let statement: Statement = statement.into();
+            let statement: Statement = match keyspace {
+                Some(keyspace) => qualify_tables(&statement, &keyspace),
+                None => statement,
+            }
+            .into();
             let w = PreparedStatementWrapper {
                 prepared: self
                     .inner
                     .add_prepared_statement(&statement) // TODO: change for add_prepared_statement_to_owned after it is made public
-                    .await?,
+                    .await
+                    .map_err(ConvertedError::from_prepare_error)?,
             };
             let types = w.get_expected_types();
             ConvertedResult::Ok(types)
@@ -210,14 +317,36 @@ impl SessionWrapper {
         options: &QueryOptionsWrapper,
     ) -> JsResult<QueryResultWrapper> {
         with_custom_error_async(async || {
-            let query = self.apply_statement_options(query.into(), &options.options)?;
-            QueryResultWrapper::from_query(self.inner.execute_unpaged(query, params).await?)
+            let mut request =
+                self.track_request(|| TrackedRequest::statement(&query, &params, true));
+            let mut query = self
+                .apply_statement_options(
+                    statement_in_keyspace(query, &options.options).into(),
+                    &options.options,
+                )
+                .map_err(|error| self.fail_request(request.take(), error))?;
+            let attempts = Arc::new(AttemptErrorsListener::new(self.request_metrics.clone()));
+            query.set_history_listener(attempts.clone());
+            let contents = query.contents.clone();
+            let result = self.inner.execute_unpaged(query, params).await;
+            let result = self
+                .await_schema_agreement_after(
+                    &contents,
+                    await_schema_agreement(&options.options),
+                    result,
+                )
+                .await;
+            let query_result = self.finish_request(request, &attempts, result, |result| result)?;
+            QueryResultWrapper::from_query(query_result)
         })
         .await
     }
 
     /// Executes all statements in the provided batch. Those statements can be either prepared or unprepared.
     ///
+    /// When the first statement is unprepared, the batch is routed with the routing key of the batch
+    /// options, or the one built from the routing indexes over the parameters of the first statement.
+    ///
     /// Returns a wrapper of the result provided by the rust driver
     #[napi(ts_return_type = "Promise<QueryResultWrapper>")]
     pub async fn batch(
@@ -226,7 +355,26 @@ impl SessionWrapper {
         params: Vec<Vec<EncodedValuesWrapper>>,
     ) -> JsResult<QueryResultWrapper> {
         with_custom_error_async(async || {
-            let res = self.inner.batch(&batch.inner, params).await?;
+            let request = self.track_request(|| TrackedRequest::batch(&batch.inner, &params));
+            // The Rust driver routes the batch using the first statement, when it is prepared.
+            let routing_policy = match batch.inner.statements.first() {
+                Some(BatchStatement::Query(_)) => self.routing_key_policy(
+                    &batch.routing,
+                    params.first().map_or(&[], Vec::as_slice),
+                    batch.inner.get_load_balancing_policy(),
+                    batch.inner.get_execution_profile_handle(),
+                ),
+                _ => None,
+            };
+            let mut statement = batch.inner.clone();
+            if let Some(policy) = routing_policy {
+                statement.set_load_balancing_policy(Some(policy));
+            }
+            let attempts = Arc::new(AttemptErrorsListener::new(self.request_metrics.clone()));
+            statement.set_history_listener(attempts.clone());
+            // Batches cannot change the schema, so there is no schema agreement to wait for.
+            let result = self.inner.batch(&statement, params).await;
+            let res = self.finish_request(request, &attempts, result, |result| result)?;
             QueryResultWrapper::from_query(res)
         })
         .await
@@ -246,11 +394,28 @@ impl SessionWrapper {
         paging_state: Option<&PagingStateWrapper>,
     ) -> JsResult<PagingResultWithExecutor> {
         with_custom_error_async(async || {
-            let statement = Arc::new(self.apply_statement_options(query.into(), &options.options)?);
+            // The request is tracked again, once executed by the executor.
+            let request = self.track_request(|| TrackedRequest::statement(&query, &params, false));
+            let (mut statement, routing) = RoutingOptions::try_from(&options.options)
+                .and_then(|routing| {
+                    let statement = self.apply_statement_options(
+                        statement_in_keyspace(query, &options.options).into(),
+                        &options.options,
+                    )?;
+                    Ok((statement, routing))
+                })
+                .map_err(|error| self.fail_request(request, error))?;
+            self.apply_routing_key(&mut statement, &routing, &params);
+            let statement = Arc::new(statement);
 
             let params = Arc::new(params);
 
-            let executor = QueryExecutor::new(statement, params, false);
+            let executor = QueryExecutor::new(
+                statement,
+                params,
+                false,
+                await_schema_agreement(&options.options),
+            );
 
             let res = executor
                 .fetch_next_page_internal(self, paging_state)
@@ -275,11 +440,24 @@ impl SessionWrapper {
         paging_state: Option<&PagingStateWrapper>,
     ) -> JsResult<PagingResultWithExecutor> {
         with_custom_error_async(async || {
-            let statement = Arc::new(self.apply_statement_options(query.into(), &options.options)?);
+            // The request is tracked again, once executed by the executor.
+            let request = self.track_request(|| TrackedRequest::statement(&query, &params, true));
+            let statement = Arc::new(
+                self.apply_statement_options(
+                    statement_in_keyspace(query, &options.options).into(),
+                    &options.options,
+                )
+                .map_err(|error| self.fail_request(request, error))?,
+            );
 
             let params = Arc::new(params);
 
-            let executor = QueryExecutor::new(statement, params, true);
+            let executor = QueryExecutor::new(
+                statement,
+                params,
+                true,
+                await_schema_agreement(&options.options),
+            );
 
             let res = executor
                 .fetch_next_page_internal(self, paging_state)
@@ -290,6 +468,41 @@ impl SessionWrapper {
         .await
     }
 
+    /// Waits until all the nodes agree on the schema version, checking it every schema agreement interval.
+    ///
+    /// Returns the agreed schema version, or fails with `SchemaAgreementTimeoutError`,
+    /// if the agreement is not reached within the schema agreement timeout.
+    #[napi(ts_return_type = "Promise<string>")]
+    pub async fn await_schema_agreement(&self) -> JsResult<String> {
+        with_custom_error_async(async || {
+            let version = self
+                .inner
+                .get_session()
+                .await_schema_agreement()
+                .await
+                .map_err(ConvertedError::from_schema_agreement_error)?;
+            ConvertedResult::Ok(version.to_string())
+        })
+        .await
+    }
+
+    /// Checks once whether all the nodes agree on the schema version.
+    ///
+    /// Returns the agreed schema version, or `null` if the nodes do not agree.
+    #[napi(ts_return_type = "Promise<string | null>")]
+    pub async fn check_schema_agreement(&self) -> JsResult<Option<String>> {
+        with_custom_error_async(async || {
+            let version = self
+                .inner
+                .get_session()
+                .check_schema_agreement()
+                .await
+                .map_err(ConvertedError::from_schema_agreement_error)?;
+            ConvertedResult::Ok(version.map(|version| version.to_string()))
+        })
+        .await
+    }
+
     /// Creates object representing batch of statements.
     #[napi(ts_return_type = "BatchWrapper")]
     pub fn create_batch(
@@ -298,13 +511,16 @@ impl SessionWrapper {
         options: &QueryOptionsWrapper,
     ) -> JsResult<BatchWrapper> {
         with_custom_error_sync(|| {
-            let mut batch: Batch = Default::default();
-            statements
-                .into_iter()
-                .for_each(|q| batch.append_statement(q.as_str()));
+            let mut batch = Batch::new(batch_type_from_options(&options.options)?);
+            statements.into_iter().for_each(|q| {
+                batch.append_statement(statement_in_keyspace(q, &options.options).as_str())
+            });
 
             batch = self.apply_batch_options(batch, &options.options)?;
-            ConvertedResult::Ok(BatchWrapper { inner: batch })
+            ConvertedResult::Ok(BatchWrapper {
+                inner: batch,
+                routing: RoutingOptions::try_from(&options.options)?,
+            })
         })
     }
 }
@@ -344,7 +560,7 @@ impl SessionWrapper {
             .is_none_or(|cached_state| !Arc::ptr_eq(&rust_cluster_state, &cached_state.inner))
         {
             *cache_guard = Some(JsThreadOnly::new(
-                ClusterSnapshot::new(rust_cluster_state, env)?,
+                ClusterSnapshot::new(rust_cluster_state, &self.release_versions, env)?,
                 env,
             ));
         }
@@ -356,6 +572,133 @@ impl SessionWrapper {
 
         f(snapshot)
     }
+
+    /// Starts tracking the request, when a request tracker is configured.
+    fn track_request(&self, request: impl FnOnce() -> TrackedRequest) -> Option<TrackedRequest> {
+        self.request_tracker.as_ref().map(|_| request())
+    }
+
+    /// Converts the error of the request, if it failed, recording it in the metrics of the requests,
+    /// and reports the request to the request tracker, when it is tracked.
+    fn finish_request<T>(
+        &self,
+        request: Option<TrackedRequest>,
+        attempts: &AttemptErrorsListener,
+        result: Result<T, ExecutionError>,
+        query_result: impl FnOnce(&T) -> &QueryResult,
+    ) -> ConvertedResult<T> {
+        // The summary is taken first, as converting the error consumes the attempts.
+        let summary = request.as_ref().map(|_| attempts.summary());
+        let result = result.map_err(|error| attempts.convert_error(error));
+        self.report_request(request.zip(summary), result.as_ref().map(query_result));
+        result
+    }
+
+    /// Fails the request before it is executed, e.g. because of its invalid options,
+    /// recording the error the same way as the errors of the executed requests.
+    fn fail_request(
+        &self,
+        request: Option<TrackedRequest>,
+        error: ConvertedError,
+    ) -> ConvertedError {
+        self.report_request(
+            request.map(|request| (request, AttemptsSummary::default())),
+            Err(&error),
+        );
+        error
+    }
+
+    /// Records the error of the request in the metrics of the requests,
+    /// and reports the request to the request tracker, when it is tracked.
+    fn report_request(
+        &self,
+        request: Option<(TrackedRequest, AttemptsSummary)>,
+        result: Result<&QueryResult, &ConvertedError>,
+    ) {
+        if let Err(error) = result {
+            self.request_metrics.request_failed(error);
+        }
+        if let Some((tracker, (request, summary))) = self.request_tracker.as_ref().zip(request) {
+            tracker.report(request.finish(summary, result.map_err(Clone::clone)));
+        }
+    }
+
+    /// Waits for schema agreement after the statement changed the schema, the same way
+    /// the Rust driver does when waiting automatically, unless the wait is disabled for the client,
+    /// or with the `waitForSchemaAgreement` option of the request.
+    ///
+    /// Errors of the wait fail the request, as they would in the Rust driver.
+    async fn await_schema_agreement_after<T>(
+        &self,
+        statement: &str,
+        await_option: bool,
+        result: Result<T, ExecutionError>,
+    ) -> Result<T, ExecutionError> {
+        if result.is_err()
+            || !await_option
+            || !self.schema_agreement.await_schema_agreement
+            || !is_schema_change(statement)
+        {
+            return result;
+        }
+        let session = self.inner.get_session();
+        session.await_schema_agreement().await?;
+        if self.schema_agreement.refresh_metadata {
+            session.refresh_metadata().await?;
+        }
+        result
+    }
+}
+
+/// Statement of the request, with its tables qualified with the keyspace given with the `keyspace` option.
+/// See `qualify_tables`.
+fn statement_in_keyspace(statement: String, options: &QueryOptionsObj) -> String {
+    match &options.keyspace {
+        Some(keyspace) => qualify_tables(&statement, keyspace),
+        None => statement,
+    }
+}
+
+/// Whether to wait for schema agreement after the request changes the schema.
+fn await_schema_agreement(options: &QueryOptionsObj) -> bool {
+    options.wait_for_schema_agreement.unwrap_or(true)
+}
+
+/// Checks whether the statement changes the schema: `CREATE`, `ALTER` or `DROP` statements.
+///
+/// The Rust driver does not expose whether the result of a request is a schema change,
+/// so the statement itself is checked instead. Some of these statements do not change the schema
+/// (e.g. `CREATE ROLE`), which only costs an extra schema agreement check.
+pub(crate) fn is_schema_change(statement: &str) -> bool {
+    let mut rest = statement;
+    // Comments preceding the statement are skipped.
+    loop {
+        rest = rest.trim_start();
+        if let Some(comment) = rest.strip_prefix("--").or_else(|| rest.strip_prefix("//")) {
+            rest = comment.split_once('\n').map_or("", |(_, rest)| rest);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/").map_or("", |(_, rest)| rest);
+        } else {
+            break;
+        }
+    }
+    let keyword = rest
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default();
+    ["CREATE", "ALTER", "DROP"]
+        .iter()
+        .any(|change| keyword.eq_ignore_ascii_case(change))
+}
+
+/// Picks the batch type based on the `logged` (defaults to true) and `counter` (defaults to false) options.
+fn batch_type_from_options(options: &QueryOptionsObj) -> Result<BatchType, InvalidBatchTypeError> {
+    match (options.counter.unwrap_or(false), options.logged) {
+        (true, Some(true)) => Err(InvalidBatchTypeError),
+        (true, _) => Ok(BatchType::Counter),
+        (false, Some(false)) => Ok(BatchType::Unlogged),
+        (false, _) => Ok(BatchType::Logged),
+    }
 }
 
 /// Macro to allow applying options to any query type
@@ -367,6 +710,18 @@ macro_rules! make_apply_options {
                 mut statement: $statement_type,
                 options: &QueryOptionsObj,
             ) -> ConvertedResult<$statement_type> {
+                // The profile is applied first, as its settings are overridden by the ones of the statement.
+                if let Some(name) = &options.execution_profile
+                    && name != DEFAULT_PROFILE_NAME
+                {
+                    let handle = self.execution_profiles.get(name).ok_or_else(|| {
+                        make_js_error(format!(
+                            "Execution profile \"{name}\" is not declared in the client options"
+                        ))
+                    })?;
+                    statement.set_execution_profile_handle(Some(handle.clone()));
+                }
+
                 if let Some(o) = options.consistency {
                     statement.set_consistency(
                         Consistency::try_from(o).map_err(|_| {
@@ -393,8 +748,18 @@ macro_rules! make_apply_options {
                         "Timestamp cannot overflow i64",
                     )?));
                 }
-                // TODO: Update it to allow collection of information from traced query
-                // Currently it's just passing the value, but not able to access any tracing information
+                if let Some(o) = options.read_timeout {
+                    let timeout = u32::try_from(o).map_err(|_| {
+                        make_js_error(format!("readTimeout must be a non-negative value, got {o}"))
+                    })?;
+                    // `None` would fall back to the timeout of the execution profile,
+                    // so a disabled timeout is represented by the longest possible one instead.
+                    statement.set_request_timeout(Some(
+                        timeout_from_millis(timeout).unwrap_or(Duration::MAX),
+                    ));
+                }
+
+                // The trace can be read later with `get_trace`, using the trace id of the result.
                 if let Some(o) = options.trace_query {
                     statement.set_tracing(o);
                 }
@@ -435,3 +800,27 @@ macro_rules! make_non_batch_apply_options {
 
 make_non_batch_apply_options!(Statement, apply_statement_options, statement_opt_partial);
 make_apply_options!(Batch, apply_batch_options);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_changes_are_detected() {
+        assert!(is_schema_change("CREATE TABLE t (a int PRIMARY KEY)"));
+        assert!(is_schema_change("  alter table t ADD b int"));
+        assert!(is_schema_change("Drop KEYSPACE ks"));
+        assert!(is_schema_change(
+            "-- comment\n/* multi\nline */ // another\nCREATE KEYSPACE ks"
+        ));
+    }
+
+    #[test]
+    fn other_statements_are_not_schema_changes() {
+        assert!(!is_schema_change("SELECT * FROM created"));
+        assert!(!is_schema_change("INSERT INTO t (a) VALUES (1)"));
+        assert!(!is_schema_change("-- CREATE TABLE t\nSELECT * FROM t"));
+        assert!(!is_schema_change("/* DROP TABLE t */"));
+        assert!(!is_schema_change(""));
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use async_trait::async_trait;
+use napi::bindgen_prelude::{Buffer, Promise};
+use scylla::authentication::{AuthError, AuthenticatorProvider, AuthenticatorSession};
+
+use crate::utils::js_callback::JsCallback;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+// Step of the SASL authentication of a connection, passed to the JS authenticator.
+// Values of kind match the methods of the JS Authenticator, except for `release`,
+// sent once the authentication of the connection is finished.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub enum AuthRequestObj
+where VariantName: kind {
+    InitialResponse {
+        authenticator_id, authenticatorId: u32,
+        authenticator_name, authenticatorName: String,
+    } = "initialResponse",
+    EvaluateChallenge {
+        authenticator_id, authenticatorId: u32,
+        token, token: Option<Buffer>,
+    } = "evaluateChallenge",
+    OnAuthenticationSuccess {
+        authenticator_id, authenticatorId: u32,
+        token, token: Option<Buffer>,
+    } = "onAuthenticationSuccess",
+    Release {
+        authenticator_id, authenticatorId: u32,
+    } = "release",
+});
+
+/// JS function handling the authentication requests, passed with the session options.
+///
+/// The function is called with an `AuthRequestObj`, and resolves to the response token, if any.
+pub type AuthenticateCallback = JsCallback<AuthRequestObj, Promise<Option<Buffer>>>;
+
+/// Authenticator provider implemented in JS.
+///
+/// Each connection is authenticated by a separate JS authenticator,
+/// identified by an id assigned by this provider.
+pub(crate) struct JsAuthenticatorProvider {
+    callback: AuthenticateCallback,
+    next_authenticator_id: AtomicU32,
+}
+
+impl JsAuthenticatorProvider {
+    pub(crate) fn new(callback: AuthenticateCallback) -> Self {
+        JsAuthenticatorProvider {
+            callback,
+            next_authenticator_id: AtomicU32::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl AuthenticatorProvider for JsAuthenticatorProvider {
+    async fn start_authentication_session(
+        &self,
+        authenticator_name: &str,
+    ) -> Result<(Option<Vec<u8>>, Box<dyn AuthenticatorSession>), AuthError> {
+        let session = JsAuthenticatorSession {
+            callback: self.callback.clone(),
+            authenticator_id: self.next_authenticator_id.fetch_add(1, Ordering::Relaxed),
+        };
+        let initial_response = session
+            .request(AuthRequestObj::InitialResponse {
+                authenticator_id: session.authenticator_id,
+                authenticator_name: authenticator_name.to_owned(),
+            })
+            .await?;
+        Ok((initial_response, Box::new(session)))
+    }
+}
+
+/// Authentication of a single connection, by the JS authenticator with the given id.
+struct JsAuthenticatorSession {
+    callback: AuthenticateCallback,
+    authenticator_id: u32,
+}
+
+impl JsAuthenticatorSession {
+    /// Passes the request to the JS authenticator, and waits for its response.
+    async fn request(&self, request: AuthRequestObj) -> Result<Option<Vec<u8>>, AuthError> {
+        let response = self
+            .callback
+            .call_async(request)
+            .await
+            .map_err(|e| e.reason.clone())?;
+        response
+            .await
+            .map(|token| token.map(Vec::from))
+            .map_err(|e| e.reason.clone())
+    }
+}
+
+#[async_trait]
+impl AuthenticatorSession for JsAuthenticatorSession {
+    async fn evaluate_challenge(
+        &mut self,
+        token: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, AuthError> {
+        self.request(AuthRequestObj::EvaluateChallenge {
+            authenticator_id: self.authenticator_id,
+            token: token.map(|token| token.to_vec().into()),
+        })
+        .await
+    }
+
+    async fn success(&mut self, token: Option<&[u8]>) -> Result<(), AuthError> {
+        self.request(AuthRequestObj::OnAuthenticationSuccess {
+            authenticator_id: self.authenticator_id,
+            token: token.map(|token| token.to_vec().into()),
+        })
+        .await
+        .map(|_| ())
+    }
+}
+
+impl Drop for JsAuthenticatorSession {
+    fn drop(&mut self) {
+        // The JS authenticator is no longer needed, whether the authentication succeeded or not.
+        // The result is ignored, as there is nothing to do, if the JS side cannot be notified.
+        self.callback.call(AuthRequestObj::Release {
+            authenticator_id: self.authenticator_id,
+        });
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::net::{IpAddr, SocketAddr};
+use std::num::{NonZeroU32, NonZeroUsize};
 use std::sync::Arc;
+use std::time::Duration;
 
 use napi::bindgen_prelude::BigInt;
 use openssl::pkcs12::Pkcs12;
@@ -11,17 +14,37 @@ use openssl::ssl::{
 };
 use openssl::x509::X509;
 use openssl::x509::store::X509StoreBuilder;
-use scylla::client::SelfIdentity;
-use scylla::client::execution_profile::ExecutionProfileBuilder;
+use scylla::client::execution_profile::{ExecutionProfileBuilder, ExecutionProfileHandle};
 use scylla::client::session_builder::SessionBuilder;
+use scylla::client::{PoolSize, SelfIdentity};
 use scylla::policies::host_filter::AllowListHostFilter;
 use scylla::policies::load_balancing::{self, LoadBalancingPolicy};
-use scylla::policies::retry::{DefaultRetryPolicy, FallthroughRetryPolicy, RetryPolicy};
+use scylla::policies::reconnect::{
+    ConstantReconnectPolicy, ExponentialReconnectPolicy, ReconnectPolicy,
+};
+use scylla::policies::retry::{
+    DefaultRetryPolicy, DowngradingConsistencyRetryPolicy, FallthroughRetryPolicy, RetryPolicy,
+};
+use scylla::policies::speculative_execution::{
+    PercentileSpeculativeExecutionPolicy, SimpleSpeculativeExecutionPolicy,
+    SpeculativeExecutionPolicy,
+};
+use scylla::policies::timestamp_generator::MonotonicTimestampGenerator;
+use scylla::statement::{Consistency, SerialConsistency};
 
 use crate::errors::{ConvertedError, ConvertedResult, make_js_error};
+use crate::metadata::events::ClusterEventsCallback;
 use crate::options;
+use crate::requests::metrics::MetricsEventsCallback;
+use crate::requests::tracker::RequestTrackerCallback;
+use crate::session::authentication::{AuthenticateCallback, JsAuthenticatorProvider};
+use crate::session::load_balancing::{JsLoadBalancingPolicy, QueryPlanCallback};
+use crate::session::retry::{
+    IdempotenceAwareRetryPolicy, JsRetryPolicy, MaxRetriesPolicy, RetryDecisionCallback,
+};
 use crate::types::type_helpers::SocketAddrWrapper;
 use crate::utils::from_napi_obj::define_js_to_rust_convertible_object;
+use crate::utils::timeout_from_millis;
 #[derive(Debug, PartialEq, Eq)]
 #[napi]
 pub enum TlsVersion {
@@ -62,7 +85,7 @@ pub struct LoadBalancingConfig {
     permit_dc_failover, permitDcFailover: bool,
     enable_shuffling_replicas, enableShufflingReplicas: bool,
     allow_list, allowList: Vec<String>,
-    
+    query_plan, queryPlan: QueryPlanCallback,
 });
 
 #[derive(Debug, PartialEq, Eq)]
@@ -70,8 +93,70 @@ pub struct LoadBalancingConfig {
 pub enum RetryPolicyKind {
     Default,
     Fallthrough,
+    /// Retry policy implemented in JS, making decisions through the `decide` callback.
+    Custom,
+    DowngradingConsistency,
+    /// Wraps the policy of `childKind`, not retrying requests that are not idempotent.
+    IdempotenceAware,
 }
 
+// Retry policy of the client or of an execution profile.
+// The `decide` callback is required for the custom retry policy, and ignored otherwise.
+// `maxRetries` limits the number of retries of the default and downgrading consistency policies,
+// which otherwise retry each kind of error at most once.
+// For the idempotence aware policy, the other fields configure the wrapped policy.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_js_to_rust_convertible_object!(
+pub struct RetryPolicyConfig {
+    kind, kind: RetryPolicyKind,
+    decide, decide: RetryDecisionCallback,
+    max_retries, maxRetries: u32,
+    child_kind, childKind: RetryPolicyKind,
+});
+
+// Settings of an execution profile, declared with the `profiles` client option.
+// Settings that are not provided fall back to the ones of the client.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_js_to_rust_convertible_object!(
+pub struct ExecutionProfileConfig {
+    name, name: String,
+    consistency, consistency: u16,
+    serial_consistency, serialConsistency: i16,
+    request_timeout, requestTimeout: u32,
+    load_balancing_config, loadBalancingConfig: LoadBalancingConfig,
+    retry_policy, retryPolicy: RetryPolicyConfig,
+});
+
+// Settings of the built-in speculative execution policies.
+// Exactly one of `delay` (constant policy) and `percentile` (percentile policy) is expected.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_js_to_rust_convertible_object!(
+#[derive(Debug, PartialEq)]
+pub struct SpeculativeExecutionConfig {
+    max_speculative_executions, maxSpeculativeExecutions: u32,
+    delay, delay: u32,
+    percentile, percentile: f64,
+});
+
+// Settings of the built-in reconnection policies.
+// Either `delay` (constant policy) or both `baseDelay` and `maxDelay` (exponential policy) are expected.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_js_to_rust_convertible_object!(
+pub struct ReconnectionPolicyConfig {
+    delay, delay: u32,
+    base_delay, baseDelay: u32,
+    max_delay, maxDelay: u32,
+});
+
+// Settings of the monotonic client-side timestamp generator.
+// When the config is not provided, timestamps are generated by the server.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_js_to_rust_convertible_object!(
+pub struct TimestampGeneratorConfig {
+    warning_threshold, warningThreshold: u32,
+    min_log_interval, minLogInterval: u32,
+});
+
 // For now, we support only fixed address translator.
 // Once we decide to support more, we can come up with more generic configuration.
 define_js_to_rust_convertible_object!(
@@ -80,7 +165,10 @@ pub struct FixedAddressTranslatorConfig {
     address_mapping, addressMapping: Vec<(SocketAddrWrapper, SocketAddrWrapper)>,
 });
 
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
 define_js_to_rust_convertible_object!(
+// Eq cannot be derived, as the speculative execution config contains a float.
+#[derive(Debug, PartialEq)]
 pub struct SessionOptions {
     connect_points, connectPoints: Vec<String>,
     keyspace, keyspace: String,
@@ -89,11 +177,29 @@ pub struct SessionOptions {
     client_id, clientId: String,
     credentials_username, credentialsUsername: String,
     credentials_password, credentialsPassword: String,
+    authenticate, authenticate: AuthenticateCallback,
+    request_tracker, requestTracker: RequestTrackerCallback,
+    metrics_events, metricsEvents: MetricsEventsCallback,
+    cluster_events, clusterEvents: ClusterEventsCallback,
     cache_size, cacheSize: u32,
     ssl_options, sslOptions: SslOptions,
     load_balancing_config, loadBalancingConfig: LoadBalancingConfig,
-    retry_policy, retryPolicy: RetryPolicyKind,
+    retry_policy, retryPolicy: RetryPolicyConfig,
     address_translator_config, addressTranslatorConfig: FixedAddressTranslatorConfig,
+    request_timeout, requestTimeout: u32,
+    execution_profiles, executionProfiles: Vec<ExecutionProfileConfig>,
+    speculative_execution, speculativeExecution: SpeculativeExecutionConfig,
+    timestamp_generator, timestampGenerator: TimestampGeneratorConfig,
+    reconnection_policy, reconnectionPolicy: ReconnectionPolicyConfig,
+    connections_per_host, connectionsPerHost: u32,
+    connections_per_shard, connectionsPerShard: u32,
+    keepalive_interval, keepaliveInterval: u32,
+    connect_timeout, connectTimeout: u32,
+    tcp_nodelay, tcpNodelay: bool,
+    tcp_keepalive_interval, tcpKeepaliveInterval: u32,
+    default_port, defaultPort: u16,
+    schema_agreement_timeout, schemaAgreementTimeout: u32,
+    schema_agreement_interval, schemaAgreementInterval: u32,
 });
 
 impl Debug for SslOptions {
@@ -268,12 +374,25 @@ fn configure_ssl(options: &SslOptions) -> ConvertedResult<Option<SslContext>> {
     Ok(Some(ssl_context_builder.build()))
 }
 
+// Traces are written asynchronously by the nodes, so they may not be complete
+// right after the traced request finishes. The Rust driver reads them for only a few milliseconds by default.
+pub(crate) const TRACE_FETCH_ATTEMPTS: NonZeroU32 = NonZeroU32::new(5).unwrap();
+pub(crate) const TRACE_FETCH_INTERVAL: Duration = Duration::from_millis(400);
+
 pub(crate) fn configure_session_builder(
     options: SessionOptions,
 ) -> ConvertedResult<SessionBuilder> {
     let mut builder = SessionBuilder::new();
     builder = builder.custom_identity(self_identity(&options));
-    builder = builder.known_nodes(options.connect_points.as_deref().unwrap_or(&[]));
+    let connect_points = options.connect_points.as_deref().unwrap_or(&[]);
+    builder = match options.default_port {
+        Some(port) => builder.known_nodes(
+            connect_points
+                .iter()
+                .map(|contact_point| with_default_port(contact_point, port)),
+        ),
+        None => builder.known_nodes(connect_points),
+    };
     if let Some(keyspace) = &options.keyspace {
         builder = builder.use_keyspace(keyspace, false);
     }
@@ -284,7 +403,13 @@ pub(crate) fn configure_session_builder(
         (Some(username), Some(password)) => {
             builder = builder.user(username, password);
         }
-        (None, None) => (),
+        (None, None) => {
+            if let Some(authenticate) = &options.authenticate {
+                builder = builder.authenticator_provider(Arc::new(JsAuthenticatorProvider::new(
+                    authenticate.clone(),
+                )));
+            }
+        }
         (Some(_), None) | (None, Some(_)) => {
             unreachable!(
                 "There is a check in JS Client constructor that should have prevented only one credential passed"
@@ -305,19 +430,62 @@ pub(crate) fn configure_session_builder(
         builder = builder.host_filter(host_filter);
     }
 
-    let mut exec_profile_builder = ExecutionProfileBuilder::default();
-    if let Some(load_balancing_policy) =
-        create_load_balancing_policy(&options.load_balancing_config)?
+    if let Some(pool_size) = pool_size(&options)? {
+        builder = builder.pool_size(pool_size);
+    }
+
+    match options.keepalive_interval {
+        None => (),
+        // Heartbeats are disabled with an interval of 0.
+        Some(0) => builder.config.keepalive_interval = None,
+        Some(interval) => {
+            builder = builder.keepalive_interval(Duration::from_millis(interval.into()));
+        }
+    }
+
+    match options.schema_agreement_timeout {
+        None => (),
+        // Waiting for schema agreement after schema changes is disabled with a timeout of 0.
+        Some(0) => builder = builder.auto_await_schema_agreement(false),
+        Some(timeout) => {
+            builder = builder.schema_agreement_timeout(Duration::from_millis(timeout.into()));
+        }
+    }
+
+    if let Some(interval) = options
+        .schema_agreement_interval
+        .and_then(timeout_from_millis)
     {
-        exec_profile_builder = exec_profile_builder.load_balancing_policy(load_balancing_policy);
+        builder = builder.schema_agreement_interval(interval);
     }
 
-    if let Some(retry_policy) = &options.retry_policy {
-        let policy: Arc<dyn RetryPolicy> = match retry_policy {
-            RetryPolicyKind::Default => Arc::new(DefaultRetryPolicy::new()),
-            RetryPolicyKind::Fallthrough => Arc::new(FallthroughRetryPolicy::new()),
-        };
-        exec_profile_builder = exec_profile_builder.retry_policy(policy);
+    if let Some(connect_timeout) = options.connect_timeout.and_then(timeout_from_millis) {
+        builder = builder.connection_timeout(connect_timeout);
+    }
+
+    if let Some(tcp_nodelay) = options.tcp_nodelay {
+        builder = builder.tcp_nodelay(tcp_nodelay);
+    }
+
+    // TCP keepalive is enabled only when the idle time before sending the probes is provided.
+    if let Some(tcp_keepalive_interval) =
+        options.tcp_keepalive_interval.and_then(timeout_from_millis)
+    {
+        builder = builder.tcp_keepalive_interval(tcp_keepalive_interval);
+    }
+
+    builder = builder
+        .tracing_info_fetch_attempts(TRACE_FETCH_ATTEMPTS)
+        .tracing_info_fetch_interval(TRACE_FETCH_INTERVAL);
+
+    let mut exec_profile_builder = client_profile_builder(&options)?;
+    if let Some(default_profile) = options
+        .execution_profiles
+        .iter()
+        .flatten()
+        .find(|profile| profile.name.as_deref() == Some(DEFAULT_PROFILE_NAME))
+    {
+        exec_profile_builder = apply_profile_config(exec_profile_builder, default_profile)?;
     }
 
     if let Some(address_translator_config) = options.address_translator_config
@@ -331,10 +499,269 @@ pub(crate) fn configure_session_builder(
         builder = builder.address_translator(Arc::new(address_mapping));
     }
 
+    if let Some(reconnection_policy) = &options.reconnection_policy {
+        builder.config.reconnect_policy = create_reconnection_policy(reconnection_policy)?;
+    }
+
+    if let Some(timestamp_generator) = &options.timestamp_generator {
+        builder =
+            builder.timestamp_generator(Arc::new(create_timestamp_generator(timestamp_generator)));
+    }
+
     builder = builder.default_execution_profile_handle(exec_profile_builder.build().into_handle());
     Ok(builder)
 }
 
+/// Name of the execution profile used by requests that do not select a profile.
+pub(crate) const DEFAULT_PROFILE_NAME: &str = "default";
+
+/// Creates the builder of an execution profile with the settings of the client.
+fn client_profile_builder(options: &SessionOptions) -> ConvertedResult<ExecutionProfileBuilder> {
+    let mut exec_profile_builder = ExecutionProfileBuilder::default();
+    if let Some(load_balancing_policy) =
+        create_load_balancing_policy(&options.load_balancing_config)?
+    {
+        exec_profile_builder = exec_profile_builder.load_balancing_policy(load_balancing_policy);
+    }
+
+    if let Some(retry_policy) = &options.retry_policy {
+        exec_profile_builder =
+            exec_profile_builder.retry_policy(create_retry_policy(retry_policy)?);
+    }
+
+    if let Some(request_timeout) = options.request_timeout {
+        exec_profile_builder =
+            exec_profile_builder.request_timeout(timeout_from_millis(request_timeout));
+    }
+
+    if let Some(speculative_execution) = &options.speculative_execution {
+        exec_profile_builder = exec_profile_builder.speculative_execution_policy(Some(
+            create_speculative_execution_policy(speculative_execution)?,
+        ));
+    }
+    Ok(exec_profile_builder)
+}
+
+/// Appends the port to the contact point, unless the contact point already specifies one.
+///
+/// Contact points are either IP addresses or hostnames, optionally followed by a port.
+/// IPv6 addresses with a port are expected to be enclosed in square brackets.
+fn with_default_port(contact_point: &str, port: u16) -> String {
+    if contact_point.parse::<SocketAddr>().is_ok() {
+        return contact_point.to_owned();
+    }
+    if let Ok(ip) = contact_point.parse::<IpAddr>() {
+        return SocketAddr::new(ip, port).to_string();
+    }
+    if contact_point.contains(':') {
+        // A hostname with a port.
+        return contact_point.to_owned();
+    }
+    format!("{contact_point}:{port}")
+}
+
+/// Returns the size of the connection pool kept for each node, if it is configured.
+///
+/// At most one of `connectionsPerHost` and `connectionsPerShard` can be provided.
+/// Cassandra nodes are treated as nodes with a single shard.
+fn pool_size(options: &SessionOptions) -> ConvertedResult<Option<PoolSize>> {
+    let non_zero = |connections: u32| {
+        NonZeroUsize::new(connections as usize)
+            .ok_or_else(|| make_js_error("The number of connections in a pool must be positive"))
+    };
+    Ok(
+        match (options.connections_per_host, options.connections_per_shard) {
+            (None, None) => None,
+            (Some(connections), None) => Some(PoolSize::PerHost(non_zero(connections)?)),
+            (None, Some(connections)) => Some(PoolSize::PerShard(non_zero(connections)?)),
+            (Some(_), Some(_)) => {
+                return Err(make_js_error(
+                    "Only one of connections per host and connections per shard can be provided",
+                )
+                .into());
+            }
+        },
+    )
+}
+
+/// Creates the reconnection policy described by the config.
+///
+/// The policy controls the delays between the attempts to fill the connection pool of a host,
+/// after the connections to that host are lost.
+fn create_reconnection_policy(
+    config: &ReconnectionPolicyConfig,
+) -> ConvertedResult<Arc<dyn ReconnectPolicy>> {
+    match (config.delay, config.base_delay, config.max_delay) {
+        (Some(delay), None, None) => Ok(Arc::new(ConstantReconnectPolicy::new(
+            Duration::from_millis(delay.into()),
+        ))),
+        (None, Some(base_delay), Some(max_delay)) if base_delay <= max_delay => Ok(Arc::new(
+            ExponentialReconnectPolicy::new().with_backoff_limits(
+                Duration::from_millis(base_delay.into()),
+                Duration::from_millis(max_delay.into()),
+            ),
+        )),
+        (None, Some(base_delay), Some(max_delay)) => Err(make_js_error(format!(
+            "Reconnection base delay ({base_delay}) must not be greater than max delay ({max_delay})"
+        ))
+        .into()),
+        _ => Err(make_js_error(
+            "Either delay or both baseDelay and maxDelay must be provided for reconnection policy",
+        )
+        .into()),
+    }
+}
+
+/// Creates the monotonic timestamp generator described by the config.
+///
+/// The generated timestamps are used for all requests that do not have their timestamp set explicitly.
+/// A warning is logged when the generated timestamps drift into the future by at least
+/// `warningThreshold` milliseconds, no more often than once every `minLogInterval` milliseconds.
+/// A `minLogInterval` of 0 disables the warnings.
+fn create_timestamp_generator(config: &TimestampGeneratorConfig) -> MonotonicTimestampGenerator {
+    let generator = MonotonicTimestampGenerator::new();
+    match config.min_log_interval {
+        Some(0) => generator.without_warnings(),
+        min_log_interval => generator.with_warning_times(
+            Duration::from_millis(config.warning_threshold.unwrap_or(1000).into()),
+            Duration::from_millis(min_log_interval.unwrap_or(1000).into()),
+        ),
+    }
+}
+
+/// Creates the speculative execution policy described by the config.
+///
+/// The Rust driver executes speculatively only the statements marked as idempotent,
+/// so the `isIdempotent` query option decides whether the policy applies to a request.
+fn create_speculative_execution_policy(
+    config: &SpeculativeExecutionConfig,
+) -> ConvertedResult<Arc<dyn SpeculativeExecutionPolicy>> {
+    let max_retry_count = config
+        .max_speculative_executions
+        .ok_or_else(|| make_js_error("maxSpeculativeExecutions must be provided"))?
+        as usize;
+    match (config.delay, config.percentile) {
+        (Some(delay), None) => Ok(Arc::new(SimpleSpeculativeExecutionPolicy {
+            max_retry_count,
+            retry_interval: Duration::from_millis(delay.into()),
+        })),
+        (None, Some(percentile)) if percentile > 0.0 && percentile < 100.0 => {
+            Ok(Arc::new(PercentileSpeculativeExecutionPolicy {
+                max_retry_count,
+                percentile,
+            }))
+        }
+        (None, Some(percentile)) => Err(make_js_error(format!(
+            "Invalid speculative execution percentile: {percentile}"
+        ))
+        .into()),
+        _ => Err(make_js_error(
+            "Exactly one of delay and percentile must be provided for speculative execution",
+        )
+        .into()),
+    }
+}
+
+/// Overrides the settings of the profile builder with the ones provided in the profile config.
+fn apply_profile_config(
+    mut builder: ExecutionProfileBuilder,
+    config: &ExecutionProfileConfig,
+) -> ConvertedResult<ExecutionProfileBuilder> {
+    if let Some(consistency) = config.consistency {
+        builder = builder.consistency(
+            Consistency::try_from(consistency)
+                .map_err(|_| make_js_error(format!("Unknown consistency value: {consistency}")))?,
+        );
+    }
+    if let Some(serial_consistency) = config.serial_consistency {
+        builder = builder.serial_consistency(Some(
+            SerialConsistency::try_from(serial_consistency).map_err(|_| {
+                make_js_error(format!(
+                    "Unknown serial consistency value: {serial_consistency}"
+                ))
+            })?,
+        ));
+    }
+    if let Some(request_timeout) = config.request_timeout {
+        builder = builder.request_timeout(timeout_from_millis(request_timeout));
+    }
+    // Hosts allowed by the allow list are filtered for the whole client, not per profile.
+    if let Some(load_balancing_policy) =
+        create_load_balancing_policy(&config.load_balancing_config)?
+    {
+        builder = builder.load_balancing_policy(load_balancing_policy);
+    }
+    if let Some(retry_policy) = &config.retry_policy {
+        builder = builder.retry_policy(create_retry_policy(retry_policy)?);
+    }
+    Ok(builder)
+}
+
+/// Creates the handles of the execution profiles declared in the options, by name.
+///
+/// The default profile is not included, as it is set as the default profile of the session.
+pub(crate) fn create_execution_profiles(
+    options: &SessionOptions,
+) -> ConvertedResult<HashMap<String, ExecutionProfileHandle>> {
+    let client_builder = client_profile_builder(options)?;
+    options
+        .execution_profiles
+        .iter()
+        .flatten()
+        .filter_map(|config| {
+            config
+                .name
+                .as_ref()
+                .filter(|name| name.as_str() != DEFAULT_PROFILE_NAME)
+                .map(|name| (name, config))
+        })
+        .map(|(name, config)| {
+            let profile = apply_profile_config(client_builder.clone(), config)?.build();
+            Ok((name.clone(), profile.into_handle_with_label(name.clone())))
+        })
+        .collect()
+}
+
+fn create_retry_policy(config: &RetryPolicyConfig) -> napi::Result<Arc<dyn RetryPolicy>> {
+    match &config.kind {
+        Some(RetryPolicyKind::IdempotenceAware) => Ok(Arc::new(IdempotenceAwareRetryPolicy::new(
+            create_base_retry_policy(config.child_kind.as_ref(), config)?,
+        ))),
+        kind => create_base_retry_policy(kind.as_ref(), config),
+    }
+}
+
+/// Creates the retry policy of the given kind, with the settings of the config.
+fn create_base_retry_policy(
+    kind: Option<&RetryPolicyKind>,
+    config: &RetryPolicyConfig,
+) -> napi::Result<Arc<dyn RetryPolicy>> {
+    let policy: Arc<dyn RetryPolicy> = match kind {
+        None | Some(RetryPolicyKind::Default) => Arc::new(DefaultRetryPolicy::new()),
+        Some(RetryPolicyKind::DowngradingConsistency) => {
+            Arc::new(DowngradingConsistencyRetryPolicy::new())
+        }
+        Some(RetryPolicyKind::Fallthrough) => return Ok(Arc::new(FallthroughRetryPolicy::new())),
+        Some(RetryPolicyKind::Custom) => {
+            return match &config.decide {
+                Some(decide) => Ok(Arc::new(JsRetryPolicy::new(decide.clone()))),
+                None => Err(make_js_error(
+                    "Custom retry policy requires the decide callback",
+                )),
+            };
+        }
+        Some(RetryPolicyKind::IdempotenceAware) => {
+            return Err(make_js_error(
+                "Idempotence aware retry policy cannot wrap another idempotence aware policy",
+            ));
+        }
+    };
+    Ok(match config.max_retries {
+        Some(max_retries) => Arc::new(MaxRetriesPolicy::new(policy, max_retries)),
+        None => policy,
+    })
+}
+
 fn create_load_balancing_policy(
     config: &Option<LoadBalancingConfig>,
 ) -> napi::Result<Option<Arc<dyn LoadBalancingPolicy>>> {
@@ -342,6 +769,14 @@ fn create_load_balancing_policy(
         return Ok(None);
     };
 
+    // Policy implemented in JS computes the whole query plan,
+    // so the options of the default policy do not apply.
+    if let Some(query_plan) = &config.query_plan {
+        return Ok(Some(Arc::new(JsLoadBalancingPolicy::new(
+            query_plan.clone(),
+        ))));
+    }
+
     let mut builder = load_balancing::DefaultPolicyBuilder::new();
 
     match (&config.prefer_datacenter, &config.prefer_rack) {
@@ -388,3 +823,22 @@ fn self_identity(options: &SessionOptions) -> SelfIdentity<'static> {
     }
     self_identity
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_port_is_applied_to_contact_points_without_port() {
+        assert_eq!(with_default_port("127.0.0.1", 9043), "127.0.0.1:9043");
+        assert_eq!(with_default_port("::1", 9043), "[::1]:9043");
+        assert_eq!(with_default_port("localhost", 9043), "localhost:9043");
+    }
+
+    #[test]
+    fn port_of_contact_point_is_kept() {
+        assert_eq!(with_default_port("127.0.0.1:9042", 9043), "127.0.0.1:9042");
+        assert_eq!(with_default_port("[::1]:9042", 9043), "[::1]:9042");
+        assert_eq!(with_default_port("localhost:9042", 9043), "localhost:9042");
+    }
+}
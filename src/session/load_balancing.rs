@@ -0,0 +1,108 @@
+use napi::bindgen_prelude::BigInt;
+use scylla::cluster::{ClusterState, NodeRef};
+use scylla::policies::load_balancing::{FallbackPlan, LoadBalancingPolicy, RoutingInfo};
+use scylla::routing::Shard;
+use uuid::Uuid;
+
+use crate::utils::js_callback::JsCallback;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+// Routing information of a request, passed to the JS load balancing policy.
+// The table is not known for unprepared requests.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct RoutingInfoObj {
+    token, token: Option<BigInt>,
+    keyspace, keyspace: Option<String>,
+    table, table: Option<String>,
+    consistency, consistency: u16,
+    serial_consistency, serialConsistency: Option<i16>,
+    is_lwt, isLwt: bool,
+});
+
+impl From<&RoutingInfo<'_>> for RoutingInfoObj {
+    fn from(request: &RoutingInfo<'_>) -> Self {
+        RoutingInfoObj {
+            token: request.token.map(|token| token.value().into()),
+            keyspace: request.table.map(|table| table.ks_name().to_owned()),
+            table: request
+                .table
+                .map(|table| table.table_name())
+                .filter(|table| !table.is_empty())
+                .map(str::to_owned),
+            consistency: request.consistency as u16,
+            serial_consistency: request
+                .serial_consistency
+                .map(|serial_consistency| serial_consistency as i16),
+            is_lwt: request.should_route_as_lwt(),
+        }
+    }
+}
+
+/// JS function computing the query plan of a request, passed with the load balancing options.
+///
+/// The function is called with a `RoutingInfoObj`, and returns the ids of the hosts to try, in order.
+pub type QueryPlanCallback = JsCallback<RoutingInfoObj, Vec<String>>;
+
+/// Load balancing policy implemented in JS.
+///
+/// The Rust driver computes query plans synchronously, so the thread executing the request
+/// is blocked, until the JS callback returns the plan. The JS callback is called once per request:
+/// no node is picked cheaply, so the whole plan is computed by the fallback.
+#[derive(Debug)]
+pub(crate) struct JsLoadBalancingPolicy {
+    callback: QueryPlanCallback,
+}
+
+impl JsLoadBalancingPolicy {
+    pub(crate) fn new(callback: QueryPlanCallback) -> Self {
+        JsLoadBalancingPolicy { callback }
+    }
+}
+
+/// Returns the node with the given id, and its shard owning the token of the request, if known.
+/// Ids of hosts that are not known to the Rust driver are skipped.
+fn plan_target<'a>(
+    cluster: &'a ClusterState,
+    request: &RoutingInfo,
+    host_id: &str,
+) -> Option<(NodeRef<'a>, Option<Shard>)> {
+    let node = cluster.get_node_by_host_id(Uuid::parse_str(host_id).ok()?)?;
+    let shard = request
+        .token
+        .zip(node.sharder())
+        .map(|(token, sharder)| sharder.shard_of(token));
+    Some((node, shard))
+}
+
+impl LoadBalancingPolicy for JsLoadBalancingPolicy {
+    fn pick<'a>(
+        &'a self,
+        _request: &'a RoutingInfo,
+        _cluster: &'a ClusterState,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        None
+    }
+
+    fn fallback<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> FallbackPlan<'a> {
+        // An empty plan fails the request, if the plan cannot be received,
+        // e.g. if the callback threw, or returned a value that is not an array of host ids.
+        let host_ids = self
+            .callback
+            .call_and_wait(RoutingInfoObj::from(request))
+            .unwrap_or_default();
+        let plan: Vec<_> = host_ids
+            .iter()
+            .filter_map(|host_id| plan_target(cluster, request, host_id))
+            .collect();
+        Box::new(plan.into_iter())
+    }
+
+    fn name(&self) -> String {
+        "JsLoadBalancingPolicy".to_owned()
+    }
+}
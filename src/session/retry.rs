@@ -0,0 +1,381 @@
+use std::sync::Arc;
+
+use scylla::errors::{DbError, RequestAttemptError};
+use scylla::policies::retry::{RequestInfo, RetryDecision, RetryPolicy, RetrySession};
+use scylla::statement::Consistency;
+
+use crate::errors::ConvertedError;
+use crate::utils::from_napi_obj::define_js_to_rust_convertible_object;
+use crate::utils::js_callback::JsCallback;
+use crate::utils::to_napi_obj::define_rust_to_js_convertible_object;
+
+// Error of a request attempt, passed to the JS retry policy.
+// Values of kind match the names of the methods of the JS RetryPolicy, that handle the error.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub enum RetryErrorObj
+where VariantName: kind {
+    ReadTimeout{
+        consistency, consistency: u16,
+        received, received: i32,
+        block_for, blockFor: i32,
+        is_data_present, isDataPresent: bool,
+    } = "onReadTimeout",
+    Unavailable{
+        consistency, consistency: u16,
+        required, required: i32,
+        alive, alive: i32,
+    } = "onUnavailable",
+    WriteTimeout{
+        consistency, consistency: u16,
+        received, received: i32,
+        block_for, blockFor: i32,
+        write_type, writeType: String,
+    } = "onWriteTimeout",
+    RequestError{
+        consistency, consistency: u16,
+        error, error: ConvertedError,
+    } = "onRequestError",
+});
+
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_rust_to_js_convertible_object!(
+pub struct RetryRequestObj {
+    nb_retry, nbRetry: u32,
+    is_idempotent, isIdempotent: bool,
+    error, error: RetryErrorObj,
+});
+
+// Decision returned by the JS retry policy.
+// Values of decision match `RetryPolicy.retryDecision` from lib/policies/retry.js.
+#[rustfmt::skip] // fmt splits each field definition into multiple lines
+define_js_to_rust_convertible_object!(
+pub struct RetryDecisionObj {
+    decision, decision: u32,
+    consistency, consistency: u16,
+    use_current_host, useCurrentHost: bool,
+});
+
+const RETHROW_DECISION: u32 = 0;
+const RETRY_DECISION: u32 = 1;
+const IGNORE_DECISION: u32 = 2;
+
+impl From<RetryDecisionObj> for RetryDecision {
+    fn from(decision: RetryDecisionObj) -> Self {
+        // An unknown consistency keeps the consistency of the request.
+        let consistency = decision
+            .consistency
+            .and_then(|consistency| Consistency::try_from(consistency).ok());
+        match decision.decision {
+            Some(RETRY_DECISION) if decision.use_current_host.unwrap_or(true) => {
+                RetryDecision::RetrySameTarget(consistency)
+            }
+            Some(RETRY_DECISION) => RetryDecision::RetryNextTarget(consistency),
+            Some(IGNORE_DECISION) => RetryDecision::IgnoreWriteError,
+            Some(RETHROW_DECISION) | Some(_) | None => RetryDecision::DontRetry,
+        }
+    }
+}
+
+/// JS function deciding whether to retry a request, passed with the retry policy options.
+///
+/// The function is called with a `RetryRequestObj`, and returns a `RetryDecisionObj`.
+pub type RetryDecisionCallback = JsCallback<RetryRequestObj, RetryDecisionObj>;
+
+/// Retry policy implemented in JS.
+///
+/// The Rust driver makes retry decisions synchronously, so the thread executing the request
+/// is blocked, until the JS callback returns the decision.
+#[derive(Debug)]
+pub(crate) struct JsRetryPolicy {
+    callback: RetryDecisionCallback,
+}
+
+impl JsRetryPolicy {
+    pub(crate) fn new(callback: RetryDecisionCallback) -> Self {
+        JsRetryPolicy { callback }
+    }
+}
+
+impl RetryPolicy for JsRetryPolicy {
+    fn new_session(&self) -> Box<dyn RetrySession> {
+        Box::new(JsRetrySession {
+            callback: self.callback.clone(),
+            nb_retry: 0,
+        })
+    }
+}
+
+struct JsRetrySession {
+    callback: RetryDecisionCallback,
+    nb_retry: u32,
+}
+
+fn retry_error(error: &RequestAttemptError, consistency: Consistency) -> RetryErrorObj {
+    match error {
+        RequestAttemptError::DbError(
+            DbError::ReadTimeout {
+                consistency,
+                received,
+                required,
+                data_present,
+            },
+            _,
+        ) => RetryErrorObj::ReadTimeout {
+            consistency: *consistency as u16,
+            received: *received,
+            block_for: *required,
+            is_data_present: *data_present,
+        },
+        RequestAttemptError::DbError(
+            DbError::Unavailable {
+                consistency,
+                required,
+                alive,
+            },
+            _,
+        ) => RetryErrorObj::Unavailable {
+            consistency: *consistency as u16,
+            required: *required,
+            alive: *alive,
+        },
+        RequestAttemptError::DbError(
+            DbError::WriteTimeout {
+                consistency,
+                received,
+                required,
+                write_type,
+            },
+            _,
+        ) => RetryErrorObj::WriteTimeout {
+            consistency: *consistency as u16,
+            received: *received,
+            block_for: *required,
+            write_type: write_type.as_str().to_owned(),
+        },
+        error => RetryErrorObj::RequestError {
+            consistency: consistency as u16,
+            error: ConvertedError::from_attempt_error(error.clone()),
+        },
+    }
+}
+
+impl RetrySession for JsRetrySession {
+    fn decide_should_retry(&mut self, request_info: RequestInfo) -> RetryDecision {
+        let request = RetryRequestObj {
+            nb_retry: self.nb_retry,
+            is_idempotent: request_info.is_idempotent,
+            error: retry_error(request_info.error, request_info.consistency),
+        };
+        // The error of the request is returned, if the decision cannot be received,
+        // e.g. if the callback threw, or returned a value that is not a valid decision.
+        let decision = self
+            .callback
+            .call_and_wait(request)
+            .map_or(RetryDecision::DontRetry, RetryDecision::from);
+        if matches!(
+            decision,
+            RetryDecision::RetrySameTarget(_) | RetryDecision::RetryNextTarget(_)
+        ) {
+            self.nb_retry += 1;
+        }
+        decision
+    }
+
+    fn reset(&mut self) {
+        self.nb_retry = 0;
+    }
+}
+
+/// Built-in retry policy allowing multiple retries of a request.
+///
+/// The built-in policies of the Rust driver retry each kind of error at most once.
+/// The session of the wrapped policy is reset after each retry, so it decides as if the error happened
+/// for the first time, while the number of retries of a request is limited to `max_retries`.
+#[derive(Debug)]
+pub(crate) struct MaxRetriesPolicy {
+    inner: Arc<dyn RetryPolicy>,
+    max_retries: u32,
+}
+
+impl MaxRetriesPolicy {
+    pub(crate) fn new(inner: Arc<dyn RetryPolicy>, max_retries: u32) -> Self {
+        MaxRetriesPolicy { inner, max_retries }
+    }
+}
+
+impl RetryPolicy for MaxRetriesPolicy {
+    fn new_session(&self) -> Box<dyn RetrySession> {
+        Box::new(MaxRetriesSession {
+            inner: self.inner.new_session(),
+            max_retries: self.max_retries,
+            nb_retry: 0,
+        })
+    }
+}
+
+struct MaxRetriesSession {
+    inner: Box<dyn RetrySession>,
+    max_retries: u32,
+    nb_retry: u32,
+}
+
+impl RetrySession for MaxRetriesSession {
+    fn decide_should_retry(&mut self, request_info: RequestInfo) -> RetryDecision {
+        match self.inner.decide_should_retry(request_info) {
+            RetryDecision::RetrySameTarget(_) | RetryDecision::RetryNextTarget(_)
+                if self.nb_retry >= self.max_retries =>
+            {
+                RetryDecision::DontRetry
+            }
+            decision @ (RetryDecision::RetrySameTarget(_) | RetryDecision::RetryNextTarget(_)) => {
+                self.nb_retry += 1;
+                self.inner.reset();
+                decision
+            }
+            decision => decision,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.nb_retry = 0;
+        self.inner.reset();
+    }
+}
+
+/// Retry policy delegating the decisions to the wrapped policy,
+/// unless retrying the request may apply it multiple times.
+///
+/// Requests that are not idempotent are not retried on write timeouts and request errors,
+/// as the request may have been applied by the node.
+#[derive(Debug)]
+pub(crate) struct IdempotenceAwareRetryPolicy {
+    child: Arc<dyn RetryPolicy>,
+}
+
+impl IdempotenceAwareRetryPolicy {
+    pub(crate) fn new(child: Arc<dyn RetryPolicy>) -> Self {
+        IdempotenceAwareRetryPolicy { child }
+    }
+}
+
+impl RetryPolicy for IdempotenceAwareRetryPolicy {
+    fn new_session(&self) -> Box<dyn RetrySession> {
+        Box::new(IdempotenceAwareRetrySession {
+            child: self.child.new_session(),
+        })
+    }
+}
+
+struct IdempotenceAwareRetrySession {
+    child: Box<dyn RetrySession>,
+}
+
+/// Read timeouts and unavailable errors are returned before the request is applied.
+fn may_be_applied(error: &RequestAttemptError) -> bool {
+    !matches!(
+        error,
+        RequestAttemptError::DbError(DbError::ReadTimeout { .. } | DbError::Unavailable { .. }, _)
+    )
+}
+
+impl RetrySession for IdempotenceAwareRetrySession {
+    fn decide_should_retry(&mut self, request_info: RequestInfo) -> RetryDecision {
+        if !request_info.is_idempotent && may_be_applied(request_info.error) {
+            return RetryDecision::DontRetry;
+        }
+        self.child.decide_should_retry(request_info)
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decision(
+        decision: u32,
+        consistency: Option<u16>,
+        use_current_host: Option<bool>,
+    ) -> RetryDecision {
+        RetryDecision::from(RetryDecisionObj {
+            decision: Some(decision),
+            consistency,
+            use_current_host,
+        })
+    }
+
+    #[test]
+    fn retry_decision_selects_target() {
+        assert_eq!(
+            decision(RETRY_DECISION, None, None),
+            RetryDecision::RetrySameTarget(None)
+        );
+        assert_eq!(
+            decision(
+                RETRY_DECISION,
+                Some(Consistency::Quorum as u16),
+                Some(false)
+            ),
+            RetryDecision::RetryNextTarget(Some(Consistency::Quorum))
+        );
+        // Unknown consistency keeps the consistency of the request.
+        assert_eq!(
+            decision(RETRY_DECISION, Some(1234), Some(true)),
+            RetryDecision::RetrySameTarget(None)
+        );
+    }
+
+    #[test]
+    fn only_timeouts_before_applying_the_request_are_safe_to_retry() {
+        let read_timeout = RequestAttemptError::DbError(
+            DbError::ReadTimeout {
+                consistency: Consistency::Quorum,
+                received: 1,
+                required: 2,
+                data_present: false,
+            },
+            String::new(),
+        );
+        let write_timeout = RequestAttemptError::DbError(
+            DbError::WriteTimeout {
+                consistency: Consistency::Quorum,
+                received: 1,
+                required: 2,
+                write_type: scylla::errors::WriteType::Simple,
+            },
+            String::new(),
+        );
+        assert!(!may_be_applied(&read_timeout));
+        assert!(may_be_applied(&write_timeout));
+        assert!(may_be_applied(&RequestAttemptError::DbError(
+            DbError::Overloaded,
+            String::new()
+        )));
+        assert!(may_be_applied(&RequestAttemptError::UnableToAllocStreamId));
+    }
+
+    #[test]
+    fn other_decisions_do_not_retry() {
+        assert_eq!(
+            decision(IGNORE_DECISION, None, None),
+            RetryDecision::IgnoreWriteError
+        );
+        assert_eq!(
+            decision(RETHROW_DECISION, Some(1), Some(true)),
+            RetryDecision::DontRetry
+        );
+        assert_eq!(decision(42, None, None), RetryDecision::DontRetry);
+        assert_eq!(
+            RetryDecision::from(RetryDecisionObj {
+                decision: None,
+                consistency: None,
+                use_current_host: None,
+            }),
+            RetryDecision::DontRetry
+        );
+    }
+}
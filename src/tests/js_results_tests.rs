@@ -1,3 +1,9 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use scylla::errors::{DbError, ExecutionError, PrepareError, RequestAttemptError, WriteType};
+use scylla::statement::Consistency;
+
 use crate::errors::{ConvertedError, JsResult, make_js_error};
 
 #[napi(ts_return_type = "number")]
@@ -6,6 +12,43 @@ pub fn tests_return_js_result(kind: i32) -> JsResult<i32> {
         1 => JsResult::Ok(1),
         2 => JsResult::Error(scylla::errors::BadKeyspaceName::Empty.into()),
         3 => JsResult::NapiError(make_js_error("Napi-error")),
+        4 => JsResult::Error(ConvertedError::from_execution_error(
+            ExecutionError::RequestTimeout(Duration::from_millis(12)),
+        )),
+        5 => JsResult::Error(ConvertedError::from_execution_error(
+            ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+                DbError::WriteTimeout {
+                    consistency: Consistency::Quorum,
+                    received: 1,
+                    required: 2,
+                    write_type: WriteType::BatchLog,
+                },
+                "Operation timed out".to_owned(),
+            )),
+        )),
+        6 => JsResult::Error(ConvertedError::from_prepare_error(
+            PrepareError::AllAttemptsFailed {
+                first_attempt: RequestAttemptError::DbError(
+                    DbError::AlreadyExists {
+                        keyspace: "ks".to_owned(),
+                        table: "tbl".to_owned(),
+                    },
+                    "Table already exists".to_owned(),
+                ),
+            },
+        )),
+        7 => JsResult::Error(
+            ConvertedError::from_execution_error(ExecutionError::EmptyPlan).with_attempt_errors([
+                (
+                    SocketAddr::from(([127, 0, 0, 1], 9042)),
+                    RequestAttemptError::DbError(DbError::Overloaded, "Overloaded".to_owned()),
+                ),
+                (
+                    SocketAddr::from(([127, 0, 0, 2], 9042)),
+                    RequestAttemptError::UnableToAllocStreamId,
+                ),
+            ]),
+        ),
         _ => unimplemented!("Unexpected test kind"),
     }
 }
@@ -2,6 +2,7 @@ pub mod js_results_tests;
 pub mod logging_tests;
 pub mod napi_ref_tests;
 pub mod option_tests;
+pub mod routing_tests;
 pub mod socket_addr_tests;
 pub mod test_utils;
 pub mod to_napi_obj_tests;
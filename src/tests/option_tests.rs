@@ -1,7 +1,8 @@
 use crate::{
     session::config::{
-        FixedAddressTranslatorConfig, LoadBalancingConfig, RetryPolicyKind, SessionOptions,
-        SslOptions, TlsVersion,
+        ExecutionProfileConfig, FixedAddressTranslatorConfig, LoadBalancingConfig,
+        ReconnectionPolicyConfig, RetryPolicyConfig, RetryPolicyKind, SessionOptions,
+        SpeculativeExecutionConfig, SslOptions, TimestampGeneratorConfig, TlsVersion,
     },
     types::type_helpers::SocketAddrWrapper,
 };
@@ -24,6 +25,10 @@ pub fn tests_check_client_option(options: SessionOptions, test_case: i32) {
                     client_id: Some("Client id".to_owned()),
                     credentials_username: Some("Unique username".to_owned()),
                     credentials_password: Some("Unique password".to_owned()),
+                    authenticate: None,
+                    request_tracker: None,
+                    metrics_events: None,
+                    cluster_events: None,
                     cache_size: Some(2137),
                     ssl_options: Some(SslOptions {
                         reject_unauthorized: Some(false),
@@ -50,8 +55,14 @@ pub fn tests_check_client_option(options: SessionOptions, test_case: i32) {
                         permit_dc_failover: Some(false),
                         enable_shuffling_replicas: Some(false),
                         allow_list: Some(vec!["127.0.0.1:7312".to_owned()]),
+                        query_plan: None,
+                    }),
+                    retry_policy: Some(RetryPolicyConfig {
+                        kind: Some(RetryPolicyKind::Default),
+                        decide: None,
+                        max_retries: Some(3),
+                        child_kind: None,
                     }),
-                    retry_policy: Some(RetryPolicyKind::Default),
                     address_translator_config: Some(FixedAddressTranslatorConfig {
                         address_mapping: Some(vec![(
                             SocketAddrWrapper {
@@ -61,7 +72,67 @@ pub fn tests_check_client_option(options: SessionOptions, test_case: i32) {
                                 socket: "7.3.1.2:960".parse().unwrap()
                             }
                         )])
-                    })
+                    }),
+                    request_timeout: Some(4321),
+                    execution_profiles: Some(vec![
+                        ExecutionProfileConfig {
+                            name: Some("analytics".to_owned()),
+                            consistency: Some(5),
+                            serial_consistency: Some(9),
+                            request_timeout: Some(30000),
+                            load_balancing_config: Some(LoadBalancingConfig {
+                                prefer_datacenter: Some("Analytics DC".to_owned()),
+                                prefer_rack: None,
+                                token_aware: None,
+                                permit_dc_failover: None,
+                                enable_shuffling_replicas: None,
+                                allow_list: None,
+                                query_plan: None,
+                            }),
+                            retry_policy: Some(RetryPolicyConfig {
+                                kind: Some(RetryPolicyKind::Fallthrough),
+                                decide: None,
+                                max_retries: None,
+                                child_kind: None,
+                            }),
+                        },
+                        ExecutionProfileConfig {
+                            name: Some("idempotent".to_owned()),
+                            consistency: None,
+                            serial_consistency: None,
+                            request_timeout: None,
+                            load_balancing_config: None,
+                            retry_policy: Some(RetryPolicyConfig {
+                                kind: Some(RetryPolicyKind::IdempotenceAware),
+                                decide: None,
+                                max_retries: None,
+                                child_kind: Some(RetryPolicyKind::DowngradingConsistency),
+                            }),
+                        }
+                    ]),
+                    speculative_execution: Some(SpeculativeExecutionConfig {
+                        max_speculative_executions: Some(2),
+                        delay: Some(100),
+                        percentile: None,
+                    }),
+                    timestamp_generator: Some(TimestampGeneratorConfig {
+                        warning_threshold: Some(2000),
+                        min_log_interval: Some(500),
+                    }),
+                    reconnection_policy: Some(ReconnectionPolicyConfig {
+                        delay: None,
+                        base_delay: Some(200),
+                        max_delay: Some(60000),
+                    }),
+                    connections_per_host: None,
+                    connections_per_shard: Some(2),
+                    keepalive_interval: Some(15000),
+                    connect_timeout: Some(1234),
+                    tcp_nodelay: Some(false),
+                    tcp_keepalive_interval: Some(300000),
+                    default_port: Some(9043),
+                    schema_agreement_timeout: Some(30000),
+                    schema_agreement_interval: Some(500),
                 }
             )
         }
@@ -76,11 +147,29 @@ pub fn tests_check_client_option(options: SessionOptions, test_case: i32) {
                     client_id: None,
                     credentials_username: None,
                     credentials_password: None,
+                    authenticate: None,
+                    request_tracker: None,
+                    metrics_events: None,
+                    cluster_events: None,
                     cache_size: None,
                     ssl_options: None,
                     load_balancing_config: None,
                     retry_policy: None,
-                    address_translator_config: None
+                    address_translator_config: None,
+                    request_timeout: None,
+                    execution_profiles: None,
+                    speculative_execution: None,
+                    timestamp_generator: None,
+                    reconnection_policy: None,
+                    connections_per_host: None,
+                    connections_per_shard: None,
+                    keepalive_interval: None,
+                    connect_timeout: None,
+                    tcp_nodelay: None,
+                    tcp_keepalive_interval: None,
+                    default_port: None,
+                    schema_agreement_timeout: None,
+                    schema_agreement_interval: None,
                 }
             )
         }
@@ -95,11 +184,29 @@ pub fn tests_check_client_option(options: SessionOptions, test_case: i32) {
                     client_id: Some("21377312-6969-4200-abcd-01234567890a".to_owned()),
                     credentials_username: Some("Unique username v2".to_owned()),
                     credentials_password: Some("Unique password v2".to_owned()),
+                    authenticate: None,
+                    request_tracker: None,
+                    metrics_events: None,
+                    cluster_events: None,
                     cache_size: None,
                     ssl_options: None,
                     load_balancing_config: None,
                     retry_policy: None,
-                    address_translator_config: None
+                    address_translator_config: None,
+                    request_timeout: None,
+                    execution_profiles: None,
+                    speculative_execution: None,
+                    timestamp_generator: None,
+                    reconnection_policy: None,
+                    connections_per_host: None,
+                    connections_per_shard: None,
+                    keepalive_interval: None,
+                    connect_timeout: None,
+                    tcp_nodelay: None,
+                    tcp_keepalive_interval: None,
+                    default_port: None,
+                    schema_agreement_timeout: None,
+                    schema_agreement_interval: None,
                 }
             )
         }
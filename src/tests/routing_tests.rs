@@ -0,0 +1,22 @@
+use napi::bindgen_prelude::BigInt;
+
+use crate::errors::{ConvertedResult, JsResult, with_custom_error_sync};
+use crate::requests::request::QueryOptionsObj;
+use crate::requests::routing::RoutingOptions;
+use crate::types::encoded_data::EncodedValuesWrapper;
+
+/// Returns the token used to route an unprepared request with the given options and parameters.
+#[napi]
+pub fn tests_routing_token(
+    options: QueryOptionsObj,
+    params: Vec<EncodedValuesWrapper>,
+) -> JsResult<Option<BigInt>> {
+    with_custom_error_sync(|| {
+        let routing = RoutingOptions::try_from(&options)?;
+        ConvertedResult::Ok(
+            routing
+                .token(&params)
+                .map(|token| BigInt::from(token.value())),
+        )
+    })
+}
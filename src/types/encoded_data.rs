@@ -10,15 +10,32 @@ use scylla::{
 
 use crate::errors::make_js_error;
 
+#[derive(Debug, PartialEq, Eq)]
 enum MaybeUnsetNullableValue<T> {
     Value(T),
     Null,
     Unset,
 }
 
+#[derive(Debug, PartialEq, Eq)]
 pub struct EncodedValuesWrapper {
     inner: MaybeUnsetNullableValue<Vec<u8>>,
 }
+
+impl EncodedValuesWrapper {
+    /// Returns the encoded value, or `None` for null and unset values.
+    pub(crate) fn as_value(&self) -> Option<&[u8]> {
+        match &self.inner {
+            MaybeUnsetNullableValue::Value(value) => Some(value),
+            MaybeUnsetNullableValue::Null | MaybeUnsetNullableValue::Unset => None,
+        }
+    }
+
+    /// Returns the size of the value, when serialized in a request, including its length prefix.
+    pub(crate) fn serialized_size(&self) -> usize {
+        4 + self.as_value().map_or(0, <[u8]>::len)
+    }
+}
 fn mk_ser_err<T: ?Sized>(
     got: &ColumnType,
     kind: impl Into<BuiltinSerializationErrorKind>,
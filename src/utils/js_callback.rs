@@ -0,0 +1,110 @@
+use std::fmt;
+use std::sync::Arc;
+
+use napi::bindgen_prelude::{FnArgs, FromNapiValue, ToNapiValue};
+use napi::sys;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+
+use crate::utils::call_and_wait;
+
+/// Shorthand for the NAPI callback type, of a JS function called with a single argument.
+///
+/// Generic parameters: `CalleeHandled = false`, `Weak = true` so that the
+/// callback does not prevent the Node.js event-loop from exiting.
+type JsCallbackFn<Arg, Return> = ThreadsafeFunction<
+    /* T: */ FnArgs<(Arg,)>,
+    /* Return: */ Return,
+    /* CallJsBackArgs: (arg) */
+    FnArgs<(Arg,)>,
+    /* ErrorStatus: */ napi::Status,
+    /* CalleeHandled: */ false,
+    /* Weak: */ true,
+>;
+
+/// JS function passed with the options, called from Rust with an `Arg`, and returning a `Return`.
+///
+/// Clones share the same threadsafe function.
+pub struct JsCallback<Arg, Return>(Arc<JsCallbackFn<Arg, Return>>)
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + 'static;
+
+impl<Arg, Return> JsCallback<Arg, Return>
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + Send + 'static,
+{
+    /// Calls the function, without waiting for it to run.
+    ///
+    /// The call is dropped, if JS cannot be called, e.g. when the process is exiting.
+    pub(crate) fn call(&self, arg: Arg) {
+        let _ = self.0.call(
+            FnArgs::from((arg,)),
+            ThreadsafeFunctionCallMode::NonBlocking,
+        );
+    }
+
+    /// Calls the function, and waits for the value it returns, blocking the current thread.
+    /// See `call_and_wait`.
+    pub(crate) fn call_and_wait(&self, arg: Arg) -> Option<Return> {
+        call_and_wait(&self.0, FnArgs::from((arg,)))
+    }
+
+    /// Calls the function, and waits for the value it returns, without blocking.
+    pub(crate) async fn call_async(&self, arg: Arg) -> napi::Result<Return> {
+        self.0.call_async_catch(FnArgs::from((arg,))).await
+    }
+}
+
+impl<Arg, Return> Clone for JsCallback<Arg, Return>
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + 'static,
+{
+    fn clone(&self) -> Self {
+        JsCallback(self.0.clone())
+    }
+}
+
+impl<Arg, Return> FromNapiValue for JsCallback<Arg, Return>
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + 'static,
+{
+    /// # Safety
+    ///
+    /// Valid pointer to napi env must be provided
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> napi::Result<Self> {
+        // Caller of this function ensures a valid pointer to napi env is provided
+        unsafe { JsCallbackFn::from_napi_value(env, napi_val) }
+            .map(|callback| JsCallback(Arc::new(callback)))
+    }
+}
+
+impl<Arg, Return> fmt::Debug for JsCallback<Arg, Return>
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JsCallback<{}>", std::any::type_name::<Arg>())
+    }
+}
+
+// Callbacks are equal, when they are the same threadsafe function.
+impl<Arg, Return> PartialEq for JsCallback<Arg, Return>
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.raw() == other.0.raw()
+    }
+}
+
+impl<Arg, Return> Eq for JsCallback<Arg, Return>
+where
+    Arg: ToNapiValue + 'static,
+    Return: FromNapiValue + 'static,
+{
+}
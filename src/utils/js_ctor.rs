@@ -5,6 +5,8 @@ use napi::bindgen_prelude::{
 use std::collections::HashMap;
 use std::sync::Mutex;
 
+use crate::errors::ConvertedError;
+use crate::metadata::host::HostStateWrapper;
 use crate::types::type_helpers::SocketAddrWrapper;
 use crate::utils::js_instance::JsInstance;
 use crate::utils::to_napi_obj::{CopyableBuffer, NamedMap};
@@ -17,6 +19,13 @@ pub mod js_constructible_class {
     pub enum SocketAddress {}
     pub enum Host {}
     pub enum HostMap {}
+    /// Plain `Record<string, KeyspaceMetadataObj>` object. It is not built through a registered
+    /// constructor, but it is pinned with a `NapiRef` just like the classes above.
+    pub enum KeyspaceMetadataMap {}
+    pub enum ResponseError {}
+    pub enum NoHostAvailableError {}
+    pub enum OperationTimedOutError {}
+    pub enum ArgumentError {}
 }
 
 /// Arguments passed to the test-only `TestJsClass(name, value)` constructor.
@@ -28,12 +37,14 @@ type TestJsClassCtorArgs<'a> = FnArgs<(&'a str, i32)>;
 /// impl produces directly.
 type SocketAddressCtorArgs = FnArgs<(SocketAddrWrapper,)>;
 
-/// Arguments passed to `Host(address, datacenter, rack, hostId)`.
+/// Arguments passed to `Host(address, datacenter, rack, hostId, state, tokens)`.
 pub(crate) type HostCtorArgs<'a> = FnArgs<(
     JsInstance<'a, js_constructible_class::SocketAddress>,
     Option<&'a str>,
     Option<&'a str>,
     CopyableBuffer<'a>,
+    HostStateWrapper,
+    Vec<String>,
 )>;
 
 /// Arguments passed to `HostMap(items)`.
@@ -43,6 +54,20 @@ pub(crate) type HostCtorArgs<'a> = FnArgs<(
 type HostMapCtorArgs<'a> =
     FnArgs<(NamedMap<String, JsInstance<'a, js_constructible_class::Host>>,)>;
 
+/// Arguments passed to `ResponseError(code, message)`.
+type ResponseErrorCtorArgs<'a> = FnArgs<(Option<i32>, &'a str)>;
+
+/// Arguments passed to `NoHostAvailableError(innerErrors, message)`.
+///
+/// `innerErrors` is a `Record<string, Error>`, keyed by the address of each host tried.
+type NoHostAvailableErrorCtorArgs<'a> = FnArgs<(NamedMap<String, ConvertedError>, &'a str)>;
+
+/// Arguments passed to `OperationTimedOutError(message)`.
+type OperationTimedOutErrorCtorArgs<'a> = FnArgs<(&'a str,)>;
+
+/// Arguments passed to `ArgumentError(message)`.
+type ArgumentErrorCtorArgs<'a> = FnArgs<(&'a str,)>;
+
 /// Defines a per-environment constructor registry for a single pure-JS class, together with:
 /// - a `#[napi]` `register_*_ctor` function that JS calls once per environment, at module load
 ///   time, to hand Rust a reference to the class's constructor;
@@ -173,7 +198,7 @@ define_js_ctor!(
 );
 
 define_js_ctor!(
-    /// `Host(address, datacenter, rack, hostId)`
+    /// `Host(address, datacenter, rack, hostId, state, tokens)`
     static_name: HOST_CTOR,
     register_fn: register_host_ctor,
     build_fn: build_host,
@@ -190,3 +215,39 @@ define_js_ctor!(
     args: HostMapCtorArgs<'_>,
     class_name: HostMap,
 );
+
+define_js_ctor!(
+    /// `ResponseError(code, message)`
+    static_name: RESPONSE_ERROR_CTOR,
+    register_fn: register_response_error_ctor,
+    build_fn: build_response_error,
+    args: ResponseErrorCtorArgs<'_>,
+    class_name: ResponseError,
+);
+
+define_js_ctor!(
+    /// `NoHostAvailableError(innerErrors, message)`
+    static_name: NO_HOST_AVAILABLE_ERROR_CTOR,
+    register_fn: register_no_host_available_error_ctor,
+    build_fn: build_no_host_available_error,
+    args: NoHostAvailableErrorCtorArgs<'_>,
+    class_name: NoHostAvailableError,
+);
+
+define_js_ctor!(
+    /// `OperationTimedOutError(message)`
+    static_name: OPERATION_TIMED_OUT_ERROR_CTOR,
+    register_fn: register_operation_timed_out_error_ctor,
+    build_fn: build_operation_timed_out_error,
+    args: OperationTimedOutErrorCtorArgs<'_>,
+    class_name: OperationTimedOutError,
+);
+
+define_js_ctor!(
+    /// `ArgumentError(message)`
+    static_name: ARGUMENT_ERROR_CTOR,
+    register_fn: register_argument_error_ctor,
+    build_fn: build_argument_error,
+    args: ArgumentErrorCtorArgs<'_>,
+    class_name: ArgumentError,
+);
@@ -28,6 +28,11 @@ impl<'env, C> JsInstance<'env, C> {
         }
     }
 
+    /// Returns the underlying `Object`, e.g. to set further properties on it.
+    pub(crate) fn into_object(self) -> Object<'env> {
+        self.object
+    }
+
     /// The raw `napi_env` this handle was created in.
     ///
     /// This is the only way to determine which N-API environment a `JsInstance` actually belongs
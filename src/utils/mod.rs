@@ -1,4 +1,5 @@
 pub mod from_napi_obj;
+pub mod js_callback;
 pub mod js_ctor;
 pub mod js_instance;
 pub mod js_thread_only;
@@ -6,10 +7,26 @@ pub mod napi_ref;
 pub mod to_napi_obj;
 
 use crate::errors::{ConvertedError, ConvertedResult, make_js_error};
-use napi::bindgen_prelude::{BigInt, Buffer};
+use napi::Status;
+use napi::bindgen_prelude::{BigInt, Buffer, FromNapiValue, JsValuesTupleIntoVec};
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
 use uuid::Uuid;
 
+/// Converts a timeout in milliseconds, as used by the JS driver options, into a Rust driver timeout.
+/// As in the JS driver, 0 disables the timeout, which is represented by `None`.
+pub(crate) fn timeout_from_millis(millis: u32) -> Option<Duration> {
+    (millis != 0).then(|| Duration::from_millis(millis.into()))
+}
+
+/// Converts the duration into `[seconds, nanoseconds]`, the format of `process.hrtime()`,
+/// in which the JS driver measures latencies.
+pub(crate) fn to_hrtime(duration: Duration) -> Vec<u32> {
+    vec![duration.as_secs() as u32, duration.subsec_nanos()]
+}
+
 /// Convert napi bigint to i64. Returns napi::Error if value doesn't fit in i64.
 pub(crate) fn bigint_to_i64(value: BigInt, error_msg: impl Display) -> ConvertedResult<i64> {
     // Currently BigInt.get_i64() doesn't work as intended, so for now convert it manually
@@ -22,6 +39,75 @@ pub(crate) fn bigint_to_i64(value: BigInt, error_msg: impl Display) -> Converted
     Ok(value.words[0] as i64 * if value.sign_bit { -1 } else { 1 })
 }
 
+/// Calls the JS function from a thread of the Rust driver, and waits for the value it returns.
+///
+/// Used by the policies, for which the Rust driver expects a synchronous decision.
+/// The calling thread is blocked until the JS thread runs the function,
+/// so it must not be called from the JS thread.
+///
+/// Returns `None` if the function could not be called, threw,
+/// or returned a value that could not be converted.
+pub(crate) fn call_and_wait<T, Return, Args, const WEAK: bool, const MAX_QUEUE_SIZE: usize>(
+    function: &ThreadsafeFunction<T, Return, Args, Status, false, WEAK, MAX_QUEUE_SIZE>,
+    value: T,
+) -> Option<Return>
+where
+    T: 'static,
+    Return: FromNapiValue + Send + 'static,
+    Args: 'static + JsValuesTupleIntoVec,
+{
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let status = function.call_with_return_value(
+        value,
+        ThreadsafeFunctionCallMode::Blocking,
+        move |result, _| {
+            // The receiver is dropped only when the caller is no longer waiting for the value.
+            let _ = sender.send(result);
+            Ok(())
+        },
+    );
+    if status != Status::Ok {
+        return None;
+    }
+    tokio::task::block_in_place(|| receiver.recv()).ok()?.ok()
+}
+
+/// Collects values and passes them together, some time after the first of them was collected.
+///
+/// Used for frequent notifications of JS, like the reports of the finished requests,
+/// to limit the number of calls into JS.
+pub(crate) struct Batcher<T> {
+    delay: Duration,
+    pending: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T: Send + 'static> Batcher<T> {
+    pub(crate) fn new(delay: Duration) -> Self {
+        Batcher {
+            delay,
+            pending: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Adds the value to the current batch. When the value starts a new batch,
+    /// `flush` is called with the whole batch after the delay of this batcher.
+    pub(crate) fn push(&self, value: T, flush: impl FnOnce(Vec<T>) + Send + 'static) {
+        let mut pending = self.pending.lock().unwrap();
+        pending.push(value);
+        if pending.len() > 1 {
+            // The batch is already scheduled to be flushed.
+            return;
+        }
+        let pending = self.pending.clone();
+        let delay = self.delay;
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let batch = std::mem::take(&mut *pending.lock().unwrap());
+            flush(batch);
+        });
+    }
+}
+
 #[derive(Default)]
 pub struct CharCounter {
     count: usize,
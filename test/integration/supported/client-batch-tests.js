@@ -16,6 +16,7 @@ describe("Client @SERVER_API", function () {
         const keyspace = helper.getRandomName("ks");
         const table1 = `${keyspace}.${helper.getRandomName("tblA")}`;
         const table2 = `${keyspace}.${helper.getRandomName("tblB")}`;
+        const counterTable = `${keyspace}.${helper.getRandomName("tblC")}`;
         before(function (done) {
             const client = newInstance();
             utils.series(
@@ -36,6 +37,11 @@ describe("Client @SERVER_API", function () {
                         client,
                         helper.createTableCql(table2),
                     ),
+                    helper.toTask(
+                        client.execute,
+                        client,
+                        `CREATE TABLE ${counterTable} (id uuid PRIMARY KEY, c counter)`,
+                    ),
                 ],
                 done,
             );
@@ -509,6 +515,47 @@ describe("Client @SERVER_API", function () {
                 done,
             );
         });
+        vit("2.0", "should execute a counter batch", async function () {
+            const client = newInstance();
+            const id = types.Uuid.random();
+            const query = `UPDATE ${counterTable} SET c = c + 1 WHERE id = ${id}`;
+            try {
+                await client.batch([query, query], { counter: true });
+                const result = await client.execute(
+                    `SELECT c FROM ${counterTable} WHERE id = ${id}`,
+                );
+                assert.strictEqual(result.first()["c"].toString(), "2");
+            } finally {
+                await client.shutdown();
+            }
+        });
+        vit(
+            "2.0",
+            "should reject a batch that is both logged and counter",
+            async function () {
+                const client = newInstance();
+                const id = types.Uuid.random();
+                try {
+                    await assert.rejects(
+                        client.batch(
+                            [
+                                `UPDATE ${counterTable} SET c = c + 1 WHERE id = ${id}`,
+                            ],
+                            { counter: true, logged: true },
+                        ),
+                        (err) => {
+                            helper.assertErrorWithName(
+                                err,
+                                "InvalidBatchTypeError",
+                            );
+                            return true;
+                        },
+                    );
+                } finally {
+                    await client.shutdown();
+                }
+            },
+        );
         describe("with no callback specified", function () {
             vit(
                 "2.0",
@@ -0,0 +1,70 @@
+"use strict";
+const assert = require("chai").assert;
+
+const helper = require("../../test-helper");
+const types = require("../../../lib/types");
+const errors = require("../../../lib/errors");
+
+describe("Client errors", function () {
+    this.timeout(120000);
+
+    const setupInfo = helper.setup(1);
+
+    it("should reject with a ResponseError on syntax errors", async function () {
+        const err = await setupInfo.client
+            .execute("SELECT WHERE FROM")
+            .then(
+                () => null,
+                (e) => e,
+            );
+        helper.assertInstanceOf(err, errors.ResponseError);
+        assert.strictEqual(err.code, types.responseErrorCodes.syntaxError);
+    });
+
+    it("should reject with a ResponseError on syntax errors when preparing", async function () {
+        const err = await setupInfo.client
+            .execute("SELECT WHERE FROM", [], { prepare: true })
+            .then(
+                () => null,
+                (e) => e,
+            );
+        helper.assertInstanceOf(err, errors.ResponseError);
+        assert.strictEqual(err.code, types.responseErrorCodes.syntaxError);
+    });
+
+    it("should expose the details of unavailable errors", async function () {
+        const table = helper.getRandomName("tbl");
+        await setupInfo.client.execute(
+            `CREATE TABLE ${table} (id int PRIMARY KEY, val text)`,
+        );
+        const err = await setupInfo.client
+            .execute(`SELECT val FROM ${table} WHERE id = 1`, [], {
+                consistency: types.consistencies.three,
+            })
+            .then(
+                () => null,
+                (e) => e,
+            );
+        helper.assertInstanceOf(err, errors.ResponseError);
+        assert.strictEqual(
+            err.code,
+            types.responseErrorCodes.unavailableException,
+        );
+        assert.strictEqual(err.consistencies, types.consistencies.three);
+        assert.strictEqual(err.required, 3);
+        assert.strictEqual(err.alive, 1);
+    });
+
+    it("should expose the keyspace of already exists errors", async function () {
+        const keyspace = helper.getRandomName("ks");
+        const query = helper.createKeyspaceCql(keyspace, 1);
+        await setupInfo.client.execute(query);
+        const err = await setupInfo.client.execute(query).then(
+            () => null,
+            (e) => e,
+        );
+        helper.assertInstanceOf(err, errors.ResponseError);
+        assert.strictEqual(err.code, types.responseErrorCodes.alreadyExists);
+        assert.strictEqual(err.keyspace, keyspace);
+    });
+});
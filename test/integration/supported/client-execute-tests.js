@@ -12,6 +12,9 @@ const ExecutionProfile =
 const types = require("../../../lib/types");
 const utils = require("../../../lib/utils.js");
 const errors = require("../../../lib/errors.js");
+const {
+    DefaultLoadBalancingPolicy,
+} = require("../../../lib/policies/load-balancing.js");
 const vit = helper.vit;
 const vdescribe = helper.vdescribe;
 const numericTests = require("./numeric-tests");
@@ -637,6 +640,36 @@ describe("Client @SERVER_API", function () {
             );
         }); */
 
+        // The profile load balancing only allows a datacenter that does not exist,
+        // so requests executed with that profile should have no host to be sent to.
+        it("should use the load balancing settings of the profile", async function () {
+            const client = newInstance({
+                profiles: [
+                    new ExecutionProfile("no-hosts", {
+                        loadBalancing: new DefaultLoadBalancingPolicy({
+                            preferDatacenter: "dc_does_not_exist",
+                            permitDcFailover: false,
+                        }),
+                    }),
+                ],
+            });
+            try {
+                await client.execute(selectAllQuery);
+                let error;
+                try {
+                    await client.execute(selectAllQuery, [], {
+                        executionProfile: "no-hosts",
+                    });
+                } catch (err) {
+                    error = err;
+                }
+                assert.ok(error, "Expected to receive an error");
+                helper.assertInstanceOf(error, errors.NoHostAvailableError);
+            } finally {
+                await client.shutdown();
+            }
+        });
+
         vit("2.2", "should accept unset as a valid value", function (done) {
             const client = setupInfo.client;
             const id = types.Uuid.random();
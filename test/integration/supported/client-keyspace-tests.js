@@ -0,0 +1,77 @@
+"use strict";
+const assert = require("chai").assert;
+
+const helper = require("../../test-helper");
+const Client = require("../../../lib/client");
+
+describe("Client with per-request keyspace", function () {
+    this.timeout(120000);
+
+    const otherKeyspace = helper.getRandomName("ks");
+    const setupInfo = helper.setup(1, {
+        queries: [
+            "CREATE TABLE tbl_keyspace (id int PRIMARY KEY, val text)",
+            helper.createKeyspaceCql(otherKeyspace),
+            `CREATE TABLE ${otherKeyspace}.tbl_keyspace (id int PRIMARY KEY, val text)`,
+            `INSERT INTO ${otherKeyspace}.tbl_keyspace (id, val) VALUES (1, 'other')`,
+            "INSERT INTO tbl_keyspace (id, val) VALUES (1, 'client')",
+        ],
+    });
+
+    const select = "SELECT val FROM tbl_keyspace WHERE id = ?";
+
+    [false, true].forEach((prepare) => {
+        it(`should execute ${prepare ? "prepared" : "unprepared"} statements in the given keyspace`, async function () {
+            const client = setupInfo.client;
+            let result = await client.execute(select, [1], {
+                prepare,
+                keyspace: otherKeyspace,
+            });
+            assert.strictEqual(result.first().val, "other");
+
+            // The same statement should still be executed in the keyspace of the client
+            result = await client.execute(select, [1], { prepare });
+            assert.strictEqual(result.first().val, "client");
+            assert.strictEqual(client.keyspace, setupInfo.keyspace);
+        });
+
+        it(`should execute ${prepare ? "prepared" : "unprepared"} fully qualified statements with a keyspace used for routing`, async function () {
+            const result = await setupInfo.client.execute(
+                `SELECT val FROM ${otherKeyspace}.tbl_keyspace WHERE id = ?`,
+                [1],
+                { prepare, keyspace: otherKeyspace },
+            );
+            assert.strictEqual(result.first().val, "other");
+        });
+    });
+
+    it("should execute batches in the given keyspace", async function () {
+        const client = setupInfo.client;
+        await client.batch(
+            [
+                {
+                    query: "INSERT INTO tbl_keyspace (id, val) VALUES (?, ?)",
+                    params: [2, "batch"],
+                },
+            ],
+            { prepare: true, keyspace: otherKeyspace },
+        );
+        const result = await client.execute(
+            `SELECT val FROM ${otherKeyspace}.tbl_keyspace WHERE id = 2`,
+        );
+        assert.strictEqual(result.first().val, "batch");
+    });
+
+    it("should execute statements in the given keyspace, when the client has no keyspace", async function () {
+        const client = new Client(helper.getOptions());
+        try {
+            const result = await client.execute(select, [1], {
+                prepare: true,
+                keyspace: otherKeyspace,
+            });
+            assert.strictEqual(result.first().val, "other");
+        } finally {
+            await client.shutdown();
+        }
+    });
+});
@@ -127,6 +127,39 @@ describe("Client", function () {
                 done,
             );
         });
+        it("should emit schemaChange", async () => {
+            const client = newInstance();
+            await client.connect();
+            const keyspace = helper.getRandomName("ks");
+            const changes = [];
+            client.on("schemaChange", (change) => changes.push(change));
+
+            await client.execute(helper.createKeyspaceCql(keyspace, 1));
+            await client.execute(
+                `CREATE TABLE ${keyspace}.tbl (id int PRIMARY KEY)`,
+            );
+            // The changes are detected shortly after the driver refreshes the schema.
+            await helper.delayAsync(5000);
+
+            assert.deepStrictEqual(
+                changes.filter((change) => change.keyspace === keyspace),
+                [
+                    {
+                        change: "created",
+                        target: "keyspace",
+                        keyspace,
+                        name: null,
+                    },
+                    {
+                        change: "created",
+                        target: "table",
+                        keyspace,
+                        name: "tbl",
+                    },
+                ],
+            );
+            await client.shutdown();
+        });
     });
 });
 
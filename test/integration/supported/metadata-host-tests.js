@@ -83,6 +83,29 @@ describe("Client#hosts", function () {
                 assert.strictEqual(hostA, hostB);
                 done();
             });
+
+            // The driver is connected to the node, which owns all the tokens of the ring.
+            it("should populate the state of the host", function (done) {
+                const host = setupInfo.client.hosts.values()[0];
+                assert.isTrue(host.isUp());
+                assert.isNotEmpty(host.tokens);
+                host.tokens.forEach((token) => assert.isString(token));
+                if (helper.getServerInfo().isScylla) {
+                    assert.isAbove(host.shardCount, 0);
+                }
+                done();
+            });
+
+            // The release version is read from the node in the background, once first requested.
+            it("should populate the release version of the host", async function () {
+                const host = setupInfo.client.hosts.values()[0];
+                // Starts reading the version.
+                host.getCassandraVersion();
+                await new Promise((resolve) => setTimeout(resolve, 2000));
+                assert.isString(host.cassandraVersion);
+                assert.isNotEmpty(host.getCassandraVersion());
+                host.getCassandraVersion().forEach((n) => assert.isNumber(n));
+            });
         });
     });
 
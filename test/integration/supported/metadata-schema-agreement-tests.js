@@ -0,0 +1,47 @@
+"use strict";
+const assert = require("chai").assert;
+
+const helper = require("../../test-helper");
+const { Uuid } = require("../../../lib/types");
+
+describe("Metadata schema agreement", function () {
+    this.timeout(120000);
+
+    const setupInfo = helper.setup(1);
+
+    it("should report that the hosts agree on the schema", async function () {
+        const agreement = await setupInfo.client.metadata.checkSchemaAgreement();
+        assert.strictEqual(agreement, true);
+    });
+
+    it("should wait for schema agreement and return the schema version", async function () {
+        const version = await setupInfo.client.metadata.awaitSchemaAgreement();
+        assert.instanceOf(version, Uuid);
+    });
+
+    it("should execute schema changes without waiting for schema agreement", async function () {
+        const client = setupInfo.client;
+        const table = helper.getRandomName("tbl");
+        await client.execute(
+            `CREATE TABLE ${table} (id int PRIMARY KEY, val text)`,
+            [],
+            { waitForSchemaAgreement: false },
+        );
+        await client.metadata.awaitSchemaAgreement();
+        await client.execute(
+            `INSERT INTO ${table} (id, val) VALUES (1, 'after agreement')`,
+        );
+        const result = await client.execute(
+            `SELECT val FROM ${table} WHERE id = 1`,
+        );
+        assert.strictEqual(result.first().val, "after agreement");
+    });
+
+    it("should support callbacks", function (done) {
+        setupInfo.client.metadata.checkSchemaAgreement((err, agreement) => {
+            assert.ifError(err);
+            assert.strictEqual(agreement, true);
+            done();
+        });
+    });
+});
@@ -0,0 +1,109 @@
+"use strict";
+const assert = require("chai").assert;
+
+const helper = require("../../test-helper");
+const types = require("../../../lib/types");
+const {
+    KeyspaceMetadata,
+    StrategyKind,
+} = require("../../../lib/metadata/keyspace-metadata");
+const {
+    TableMetadata,
+    ColumnKind,
+} = require("../../../lib/metadata/table-metadata");
+const { Udt } = require("../../../lib/metadata/user-defined-type");
+
+describe("Client#metadata schema", function () {
+    this.timeout(120000);
+
+    const setupInfo = helper.setup("1:0", {
+        queries: [
+            "CREATE TYPE phone (alias text, number text)",
+            "CREATE TABLE tbl_schema (id1 uuid, id2 int, s text static, val text, PRIMARY KEY (id1, id2))",
+        ],
+    });
+
+    // Schema changes are propagated to the Rust driver metadata asynchronously.
+    before(function (done) {
+        setupInfo.client.execute(
+            "SELECT * FROM system.local",
+            [],
+            { isIdempotent: true },
+            (err) => {
+                if (err) {
+                    return done(err);
+                }
+                setTimeout(done, 1000);
+            },
+        );
+    });
+
+    // The keyspace created by the test setup should be visible, with the replication
+    // it was created with.
+    it("should retrieve the keyspace metadata", function () {
+        const keyspace = setupInfo.client.metadata.getKeyspace(
+            setupInfo.keyspace,
+        );
+        assert.instanceOf(keyspace, KeyspaceMetadata);
+        assert.strictEqual(
+            keyspace.strategy.kind,
+            StrategyKind.NetworkTopologyStrategy,
+        );
+        assert.strictEqual(keyspace.durableWrites, false);
+        assert.containsAllKeys(keyspace.tables, ["tbl_schema"]);
+
+        assert.isTrue(
+            setupInfo.client.metadata
+                .getKeyspaces()
+                .has(setupInfo.keyspace),
+        );
+    });
+
+    // Columns, their kinds and types, as well as the primary key should match the table definition.
+    it("should retrieve the table metadata", function () {
+        const table = setupInfo.client.metadata.getTable(
+            setupInfo.keyspace,
+            "tbl_schema",
+        );
+        assert.instanceOf(table, TableMetadata);
+        assert.deepEqual(table.partitionKey, ["id1"]);
+        assert.deepEqual(table.clusteringKey, ["id2"]);
+        assert.strictEqual(table.columns.id1.kind, ColumnKind.PartitionKey);
+        assert.strictEqual(table.columns.id2.kind, ColumnKind.Clustering);
+        assert.strictEqual(table.columns.s.kind, ColumnKind.Static);
+        assert.strictEqual(table.columns.val.kind, ColumnKind.Regular);
+        assert.strictEqual(
+            table.columns.id1.type.code,
+            types.dataTypes.uuid,
+        );
+        assert.strictEqual(table.columns.id2.type.code, types.dataTypes.int);
+    });
+
+    it("should retrieve the udt metadata", function () {
+        const udt = setupInfo.client.metadata.getUdt(
+            setupInfo.keyspace,
+            "phone",
+        );
+        assert.instanceOf(udt, Udt);
+        assert.deepEqual(
+            udt.fields.map((f) => f.name),
+            ["alias", "number"],
+        );
+    });
+
+    it("should return null for unknown keyspaces and tables", function () {
+        const metadata = setupInfo.client.metadata;
+        assert.isNull(metadata.getKeyspace("ks_does_not_exist"));
+        assert.isNull(metadata.getTable(setupInfo.keyspace, "no_such_table"));
+        assert.isNull(metadata.getTable("ks_does_not_exist", "tbl_schema"));
+    });
+
+    // The same converted objects should be returned as long as the schema does not change.
+    it("should return the same instances across repeated accesses", function () {
+        const metadata = setupInfo.client.metadata;
+        assert.strictEqual(
+            metadata.getTable(setupInfo.keyspace, "tbl_schema"),
+            metadata.getTable(setupInfo.keyspace, "tbl_schema"),
+        );
+    });
+});
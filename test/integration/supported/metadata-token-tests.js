@@ -0,0 +1,62 @@
+"use strict";
+const assert = require("chai").assert;
+
+const helper = require("../../test-helper");
+const { Host } = require("../../../lib/host");
+const { TokenRange } = require("../../../lib/token");
+
+describe("Client#metadata token ring", function () {
+    this.timeout(120000);
+
+    const setupInfo = helper.setup("1:0", {
+        queries: ["CREATE TABLE tbl_tokens (id int PRIMARY KEY, val text)"],
+    });
+
+    // Every node owns at least one range of the ring.
+    it("should retrieve the token ranges of the ring", function () {
+        const ranges = setupInfo.client.metadata.getTokenRanges();
+        assert.instanceOf(ranges, Set);
+        assert.isAbove(ranges.size, 0);
+        ranges.forEach((range) => assert.instanceOf(range, TokenRange));
+    });
+
+    // Replicas should be the same Host instances as the ones exposed by Client#hosts.
+    // For tablet-based keyspaces, the replicas are only known after the driver learns the tablet.
+    it("should retrieve the replicas of a routing key", function () {
+        const routingKey = Buffer.from([0, 0, 0, 1]);
+        const replicas = setupInfo.client.getReplicas(
+            setupInfo.keyspace,
+            routingKey,
+            "tbl_tokens",
+        );
+        assert.isArray(replicas);
+        const hosts = setupInfo.client.hosts.values();
+        replicas.forEach((replica) => {
+            assert.instanceOf(replica, Host);
+            assert.include(hosts, replica);
+        });
+    });
+
+    it("should return null for unknown keyspaces", function () {
+        const metadata = setupInfo.client.metadata;
+        const host = setupInfo.client.hosts.values()[0];
+        assert.isNull(
+            metadata.getReplicas("ks_does_not_exist", Buffer.from([1])),
+        );
+        assert.isNull(
+            metadata.getTokenRangesForHost("ks_does_not_exist", host),
+        );
+    });
+
+    // system_distributed is not tablet-based, so its ranges follow the ring. With a single node,
+    // that node replicates every range of the ring.
+    it("should retrieve the token ranges of a host", function () {
+        const metadata = setupInfo.client.metadata;
+        const host = setupInfo.client.hosts.values()[0];
+        const ranges = metadata.getTokenRangesForHost(
+            "system_distributed",
+            host,
+        );
+        assert.strictEqual(ranges.size, metadata.getTokenRanges().size);
+    });
+});
@@ -0,0 +1,90 @@
+"use strict";
+const assert = require("chai").assert;
+
+const helper = require("../../test-helper");
+const types = require("../../../lib/types");
+const errors = require("../../../lib/errors");
+
+describe("Metadata trace", function () {
+    this.timeout(120000);
+
+    const setupInfo = helper.setup(1);
+
+    it("should retrieve the trace of a traced query", async function () {
+        const client = setupInfo.client;
+        const result = await client.execute(
+            "SELECT key FROM system.local",
+            [],
+            { traceQuery: true },
+        );
+        const traceId = result.info.traceId;
+        assert.instanceOf(traceId, types.Uuid);
+
+        const trace = await client.metadata.getTrace(traceId);
+        assert.isString(trace.requestType);
+        assert.instanceOf(trace.coordinator, types.InetAddress);
+        assert.instanceOf(trace.clientAddress, types.InetAddress);
+        assert.instanceOf(trace.startedAt, Date);
+        assert.isNumber(trace.duration);
+        assert.isObject(trace.parameters);
+        assert.isAbove(trace.events.length, 0);
+        const event = trace.events[0];
+        assert.instanceOf(event.id, types.TimeUuid);
+        assert.isString(event.activity);
+        assert.instanceOf(event.source, types.InetAddress);
+    });
+
+    it("should retrieve the trace using a callback", function (done) {
+        const client = setupInfo.client;
+        client.execute(
+            "SELECT key FROM system.local",
+            [],
+            { traceQuery: true },
+            (err, result) => {
+                assert.ifError(err);
+                client.metadata.getTrace(
+                    result.info.traceId,
+                    types.consistencies.one,
+                    (err, trace) => {
+                        assert.ifError(err);
+                        assert.isAbove(trace.events.length, 0);
+                        done();
+                    },
+                );
+            },
+        );
+    });
+
+    it("should read the trace with the given consistency", async function () {
+        const client = setupInfo.client;
+        const result = await client.execute(
+            "SELECT key FROM system.local",
+            [],
+            { traceQuery: true },
+        );
+        // Replicas of system_traces cannot be all up in a single node cluster
+        const err = await client.metadata
+            .getTrace(result.info.traceId, types.consistencies.all)
+            .then(
+                () => null,
+                (e) => e,
+            );
+        helper.assertInstanceOf(err, errors.ResponseError);
+        assert.strictEqual(
+            err.code,
+            types.responseErrorCodes.unavailableException,
+        );
+        assert.strictEqual(err.consistencies, types.consistencies.all);
+    });
+
+    it("should fail when the trace does not exist", async function () {
+        const err = await setupInfo.client.metadata
+            .getTrace(types.Uuid.random())
+            .then(
+                () => null,
+                (e) => e,
+            );
+        assert.instanceOf(err, Error);
+        assert.strictEqual(err.name, "TracingError");
+    });
+});
@@ -15,7 +15,7 @@ async function myTest(): Promise<any> {
 
     let promise: Promise<void>;
     let n: number;
-    let hosts: Host[];
+    let hosts: Host[] | null;
 
     promise = client.connect();
 
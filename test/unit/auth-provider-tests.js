@@ -0,0 +1,121 @@
+"use strict";
+
+const { assert } = require("chai");
+
+const { AuthProvider, Authenticator } = require("../../lib/auth");
+
+class TokenAuthenticator extends Authenticator {
+    constructor(name, events) {
+        super();
+        this.name = name;
+        this.events = events;
+    }
+    initialResponse(callback) {
+        this.events.push(["initialResponse", this.name]);
+        callback(null, Buffer.from("token"));
+    }
+    evaluateChallenge(challenge, callback) {
+        this.events.push(["evaluateChallenge", challenge.toString()]);
+        // Responds asynchronously
+        setImmediate(() => callback(null, Buffer.from("response")));
+    }
+    onAuthenticationSuccess(token) {
+        this.events.push(["onAuthenticationSuccess", token]);
+    }
+}
+
+class TokenAuthProvider extends AuthProvider {
+    constructor() {
+        super();
+        this.events = [];
+    }
+    newAuthenticator(endpoint, name) {
+        return new TokenAuthenticator(name, this.events);
+    }
+}
+
+describe("AuthProvider", function () {
+    describe("#getRustConfiguration()", function () {
+        it("should forward the authentication steps to the authenticator", async () => {
+            const provider = new TokenAuthProvider();
+            const authenticate = provider.getRustConfiguration();
+
+            const initial = await authenticate({
+                kind: "initialResponse",
+                authenticatorId: 0,
+                authenticatorName: "com.example.TokenAuthenticator",
+            });
+            assert.strictEqual(initial.toString(), "token");
+
+            const response = await authenticate({
+                kind: "evaluateChallenge",
+                authenticatorId: 0,
+                token: Buffer.from("challenge"),
+            });
+            assert.strictEqual(response.toString(), "response");
+
+            const success = await authenticate({
+                kind: "onAuthenticationSuccess",
+                authenticatorId: 0,
+                token: null,
+            });
+            assert.isNull(success);
+
+            assert.deepStrictEqual(provider.events, [
+                ["initialResponse", "com.example.TokenAuthenticator"],
+                ["evaluateChallenge", "challenge"],
+                ["onAuthenticationSuccess", undefined],
+            ]);
+        });
+
+        it("should use a separate authenticator for each connection", async () => {
+            const provider = new TokenAuthProvider();
+            const authenticators = [];
+            provider.newAuthenticator = function (endpoint, name) {
+                const authenticator = new TokenAuthenticator(name, []);
+                authenticators.push(authenticator);
+                return authenticator;
+            };
+            const authenticate = provider.getRustConfiguration();
+
+            for (const authenticatorId of [0, 1]) {
+                await authenticate({
+                    kind: "initialResponse",
+                    authenticatorId,
+                    authenticatorName: `name${authenticatorId}`,
+                });
+            }
+            await authenticate({
+                kind: "evaluateChallenge",
+                authenticatorId: 1,
+                token: Buffer.from("second"),
+            });
+
+            assert.lengthOf(authenticators, 2);
+            assert.deepStrictEqual(authenticators[0].events, [
+                ["initialResponse", "name0"],
+            ]);
+            assert.deepStrictEqual(authenticators[1].events, [
+                ["initialResponse", "name1"],
+                ["evaluateChallenge", "second"],
+            ]);
+        });
+
+        it("should reject when the authenticator fails", async () => {
+            const provider = new TokenAuthProvider();
+            provider.newAuthenticator = () => new Authenticator();
+            const authenticate = provider.getRustConfiguration();
+
+            const err = await authenticate({
+                kind: "initialResponse",
+                authenticatorId: 0,
+                authenticatorName: "name",
+            }).then(
+                () => null,
+                (e) => e,
+            );
+            assert.instanceOf(err, Error);
+            assert.strictEqual(err.message, "Not implemented");
+        });
+    });
+});
@@ -626,6 +626,10 @@ describe("exports", function () {
             typeof speculativeExecution.ConstantSpeculativeExecutionPolicy,
             "function",
         );
+        assert.strictEqual(
+            typeof speculativeExecution.PercentileSpeculativeExecutionPolicy,
+            "function",
+        );
         assert.strictEqual(
             typeof speculativeExecution.SpeculativeExecutionPolicy,
             "function",
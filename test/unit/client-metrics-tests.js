@@ -0,0 +1,125 @@
+"use strict";
+
+const { assert } = require("chai");
+
+const { ClientMetrics, DefaultMetrics } = require("../../lib/metrics");
+const errors = require("../../lib/errors");
+const { responseErrorCodes } = require("../../lib/types");
+
+class RecordingMetrics extends ClientMetrics {
+    constructor() {
+        super();
+        this.calls = [];
+        for (const method of Object.getOwnPropertyNames(
+            ClientMetrics.prototype,
+        )) {
+            if (method.startsWith("on")) {
+                this[method] = (arg) => this.calls.push([method, arg]);
+            }
+        }
+    }
+}
+
+function mockClient() {
+    const logged = [];
+    return {
+        logged,
+        log: (level, message) => logged.push([level, message]),
+    };
+}
+
+describe("ClientMetrics", function () {
+    describe("#getRustConfiguration()", function () {
+        it("should measure the responses", () => {
+            const metrics = new RecordingMetrics();
+
+            metrics.getRustConfiguration(mockClient())([
+                { kind: "response", latency: [0, 100], success: true },
+                { kind: "response", latency: [1, 200], success: false },
+                { kind: "speculativeExecution" },
+            ]);
+
+            assert.deepStrictEqual(metrics.calls, [
+                ["onResponse", [0, 100]],
+                ["onSuccessfulResponse", [0, 100]],
+                ["onResponse", [1, 200]],
+                ["onSpeculativeExecution", undefined],
+            ]);
+        });
+
+        it("should measure the errors by their kind", () => {
+            const metrics = new RecordingMetrics();
+            const readTimeout = new errors.ResponseError(
+                responseErrorCodes.readTimeout,
+                "Read timeout",
+            );
+            const unavailable = new errors.ResponseError(
+                responseErrorCodes.unavailableException,
+                "Unavailable",
+            );
+            const timeout = new errors.OperationTimedOutError("Timed out");
+            const other = new Error("Other");
+
+            metrics.getRustConfiguration(mockClient())([
+                { kind: "error", error: readTimeout },
+                { kind: "error", error: timeout },
+                { kind: "error", error: other },
+                { kind: "retry", error: unavailable },
+                { kind: "retry", error: other },
+                { kind: "ignoredError", error: other },
+            ]);
+
+            assert.deepStrictEqual(metrics.calls, [
+                ["onReadTimeoutError", readTimeout],
+                ["onClientTimeoutError", timeout],
+                ["onOtherError", other],
+                ["onUnavailableRetry", unavailable],
+                ["onOtherErrorRetry", other],
+                ["onIgnoreError", other],
+            ]);
+        });
+
+        it("should emit the events of the default metrics", () => {
+            const metrics = new DefaultMetrics();
+            const emitted = [];
+            metrics.responses.on("increment", (latency) =>
+                emitted.push(["response", latency]),
+            );
+            metrics.errors.writeTimeout.on("increment", (e) =>
+                emitted.push(["writeTimeout", e]),
+            );
+            const writeTimeout = new errors.ResponseError(
+                responseErrorCodes.writeTimeout,
+                "Write timeout",
+            );
+
+            metrics.getRustConfiguration(mockClient())([
+                { kind: "response", latency: [0, 1], success: true },
+                { kind: "error", error: writeTimeout },
+            ]);
+
+            assert.deepStrictEqual(emitted, [
+                ["response", [0, 1]],
+                ["writeTimeout", writeTimeout],
+            ]);
+        });
+
+        it("should log the errors of the metrics and continue with the next events", () => {
+            const metrics = new RecordingMetrics();
+            metrics.onSpeculativeExecution = () => {
+                throw new Error("Metrics failure");
+            };
+            const client = mockClient();
+
+            metrics.getRustConfiguration(client)([
+                { kind: "speculativeExecution" },
+                { kind: "response", latency: [0, 1], success: false },
+            ]);
+
+            assert.deepStrictEqual(metrics.calls, [["onResponse", [0, 1]]]);
+            assert.lengthOf(client.logged, 1);
+            assert.strictEqual(client.logged[0][0], "warning");
+            assert.include(client.logged[0][1], "Metrics failure");
+        });
+    });
+});
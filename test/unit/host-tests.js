@@ -7,10 +7,24 @@ const Uuid = require("../../lib/types/uuid");
 /**
  * Builds a real `Host` instance the same way the native code would (constructor is only
  * meant to be invoked by Rust in production), given an already-built address.
+ *
+ * The state of the node, read by the host from the native code, is replaced by the given values.
  */
-function makeHostAt(address, datacenter, rack, hostIdByte) {
+function makeHostAt(address, datacenter, rack, hostIdByte, state = {}) {
     const hostId = Buffer.alloc(16, hostIdByte);
-    return new Host(address, datacenter, rack, hostId);
+    const nodeState = {
+        isUp: () => state.isUp ?? true,
+        releaseVersion: () => state.cassandraVersion ?? null,
+        shardCount: () => state.shardCount ?? null,
+    };
+    return new Host(
+        address,
+        datacenter,
+        rack,
+        hostId,
+        nodeState,
+        state.tokens ?? [],
+    );
 }
 
 /**
@@ -20,13 +34,13 @@ function makeHostAt(address, datacenter, rack, hostIdByte) {
  * `family` has to be passed explicitly for IPv6, since `net.SocketAddress` defaults to `ipv4` and
  * rejects an IPv6 literal under that family. Rust always sends it explicitly.
  */
-function makeHost(ip, port, datacenter, rack, hostIdByte) {
+function makeHost(ip, port, datacenter, rack, hostIdByte, state = {}) {
     const address = new SocketAddress({
         address: ip,
         port,
         family: ip.includes(":") ? "ipv6" : "ipv4",
     });
-    return makeHostAt(address, datacenter, rack, hostIdByte);
+    return makeHostAt(address, datacenter, rack, hostIdByte, state);
 }
 
 /**
@@ -64,6 +78,61 @@ describe("Host", function () {
             assert.isNull(host.datacenter);
             assert.isNull(host.rack);
         });
+
+        it("should populate the state of the node as given", function () {
+            const address = new SocketAddress({
+                address: "127.0.0.1",
+                port: 9042,
+            });
+            const host = makeHostAt(address, "dc1", "rack1", 1, {
+                isUp: false,
+                cassandraVersion: "2025.1.3-0.20250605.1d6d2e6f9f3e",
+                shardCount: 4,
+                tokens: ["-9223372036854775808", "42"],
+            });
+
+            assert.isFalse(host.isUp());
+            assert.isFalse(host.canBeConsideredAsUp());
+            assert.strictEqual(
+                host.cassandraVersion,
+                "2025.1.3-0.20250605.1d6d2e6f9f3e",
+            );
+            assert.strictEqual(host.shardCount, 4);
+            assert.deepEqual(host.tokens, ["-9223372036854775808", "42"]);
+        });
+
+        it("should read the current state of the node", function () {
+            const state = { isUp: true };
+            const host = makeHost("127.0.0.1", 9042, "dc1", "rack1", 1, state);
+            assert.isTrue(host.isUp());
+            assert.isNull(host.cassandraVersion);
+
+            state.isUp = false;
+            state.cassandraVersion = "6.2.0";
+
+            assert.isFalse(host.isUp());
+            assert.strictEqual(host.cassandraVersion, "6.2.0");
+        });
+    });
+
+    describe("getCassandraVersion()", function () {
+        it("should return the numbers of the version", function () {
+            const address = new SocketAddress({
+                address: "127.0.0.1",
+                port: 9042,
+            });
+            const host = makeHostAt(address, "dc1", "rack1", 1, {
+                cassandraVersion: "3.0.8-SNAPSHOT",
+            });
+
+            assert.deepEqual(host.getCassandraVersion(), [3, 0, 8]);
+        });
+
+        it("should return an empty array, when the version is not known", function () {
+            const host = makeHost("127.0.0.1", 9042, "dc1", "rack1", 1);
+
+            assert.deepEqual(host.getCassandraVersion(), []);
+        });
     });
 
     describe("addressToString()", function () {
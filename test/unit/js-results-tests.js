@@ -3,6 +3,8 @@ const { assert } = require("chai");
 
 const rust = require("../../index");
 const helper = require("../test-helper");
+const types = require("../../lib/types");
+const errors = require("../../lib/errors");
 
 describe("JsResult", function () {
     it("should get the JsResult::Ok correctly", function () {
@@ -29,6 +31,70 @@ describe("JsResult", function () {
         }
     });
 
+    it("should give request timeouts a distinct error name", function () {
+        try {
+            rust.testsReturnJsResult(4);
+            assert.fail("This function should throw");
+        } catch (e) {
+            helper.assertInstanceOf(e, errors.OperationTimedOutError);
+            assert.strictEqual(
+                e.message,
+                "Request execution exceeded a client timeout of 12ms",
+            );
+            assert.strictEqual(e.name, "OperationTimedOutError");
+        }
+    });
+
+    it("should expose the details of database errors", function () {
+        try {
+            rust.testsReturnJsResult(5);
+            assert.fail("This function should throw");
+        } catch (e) {
+            helper.assertInstanceOf(e, errors.ResponseError);
+            assert.strictEqual(e.name, "ResponseError");
+            assert.strictEqual(e.code, types.responseErrorCodes.writeTimeout);
+            assert.strictEqual(e.consistencies, types.consistencies.quorum);
+            assert.strictEqual(e.received, 1);
+            assert.strictEqual(e.blockFor, 2);
+            assert.strictEqual(e.writeType, "BATCH_LOG");
+        }
+    });
+
+    it("should expose the details of database errors returned when preparing", function () {
+        try {
+            rust.testsReturnJsResult(6);
+            assert.fail("This function should throw");
+        } catch (e) {
+            helper.assertInstanceOf(e, errors.ResponseError);
+            assert.strictEqual(e.code, types.responseErrorCodes.alreadyExists);
+            assert.strictEqual(e.keyspace, "ks");
+            assert.strictEqual(e.table, "tbl");
+        }
+    });
+
+    it("should give no host available errors the errors of each host", function () {
+        try {
+            rust.testsReturnJsResult(7);
+            assert.fail("This function should throw");
+        } catch (e) {
+            helper.assertInstanceOf(e, errors.NoHostAvailableError);
+            assert.strictEqual(e.name, "NoHostAvailableError");
+            assert.deepStrictEqual(Object.keys(e.innerErrors).sort(), [
+                "127.0.0.1:9042",
+                "127.0.0.2:9042",
+            ]);
+            const overloaded = e.innerErrors["127.0.0.1:9042"];
+            helper.assertInstanceOf(overloaded, errors.ResponseError);
+            assert.strictEqual(
+                overloaded.code,
+                types.responseErrorCodes.overloaded,
+            );
+            const streamIdError = e.innerErrors["127.0.0.2:9042"];
+            helper.assertInstanceOf(streamIdError, Error);
+            assert.strictEqual(streamIdError.name, "RequestAttemptError");
+        }
+    });
+
     it("should get the correct error from JsResult::Error (async1)", function (done) {
         try {
             rust.testsReturnJsResultAsync(2).then(
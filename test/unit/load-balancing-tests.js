@@ -7,7 +7,9 @@ const {
     TokenAwarePolicy,
     RoundRobinPolicy,
     DCAwareRoundRobinPolicy,
+    LoadBalancingPolicy,
 } = require("../../lib/policies/load-balancing");
+const types = require("../../lib/types");
 
 describe("RoundRobinPolicy", function () {
     describe("#getOptions()", () => {
@@ -88,3 +90,112 @@ describe("AllowListPolicy", function () {
         });
     });
 });
+describe("custom LoadBalancingPolicy", function () {
+    class ReversePolicy extends LoadBalancingPolicy {
+        newQueryPlan(keyspace, executionOptions, callback) {
+            this.lastKeyspace = keyspace;
+            this.lastOptions = executionOptions;
+            const plan = [...this.hosts.values()].reverse();
+            callback(null, plan[Symbol.iterator]());
+        }
+    }
+
+    function newHost(id) {
+        return { hostId: types.Uuid.fromString(id) };
+    }
+
+    const ids = [
+        "8a2b6e1c-7f3d-4c1e-9b0a-1d2e3f4a5b6c",
+        "0f1e2d3c-4b5a-4968-8776-655443322110",
+    ];
+    const hosts = {
+        values: () => ids.map(newHost),
+        length: ids.length,
+    };
+    const client = { hosts, keyspace: "ks1", log: () => {} };
+
+    function initPolicy(policy) {
+        return new Promise((resolve, reject) =>
+            policy.initJsQueryPlan(client, hosts, (err) =>
+                err ? reject(err) : resolve(),
+            ),
+        );
+    }
+
+    it("should compute the query plans in JS", () => {
+        assert.strictEqual(new ReversePolicy().hasJsQueryPlan(), true);
+        assert.strictEqual(new RoundRobinPolicy().hasJsQueryPlan(), false);
+        assert.strictEqual(new LoadBalancingPolicy().hasJsQueryPlan(), false);
+        assert.throws(() => new LoadBalancingPolicy().getRustConfiguration());
+    });
+
+    it("should return an empty plan before the policy is initialized", () => {
+        const config = new ReversePolicy().getRustConfiguration();
+        assert.deepStrictEqual(
+            config.queryPlan({ consistency: 1, isLwt: false }),
+            [],
+        );
+    });
+
+    it("should return the ids of the hosts of the query plan", async () => {
+        const policy = new ReversePolicy();
+        await initPolicy(policy);
+        const plan = policy.getRustConfiguration().queryPlan({
+            token: -42n,
+            keyspace: "ks2",
+            table: "tbl",
+            consistency: types.consistencies.quorum,
+            isLwt: true,
+        });
+        assert.deepStrictEqual(plan, [...ids].reverse());
+        assert.strictEqual(policy.lastKeyspace, "ks1");
+        const options = policy.lastOptions;
+        assert.strictEqual(options.getKeyspace(), "ks2");
+        assert.strictEqual(options.getTable(), "tbl");
+        assert.strictEqual(
+            options.getConsistency(),
+            types.consistencies.quorum,
+        );
+        assert.strictEqual(options.getSerialConsistency(), undefined);
+        assert.strictEqual(options.isLwt(), true);
+        assert.strictEqual(
+            options.getRoutingToken().getValue().toString(),
+            "-42",
+        );
+    });
+
+    it("should limit the query plan to the number of hosts", async () => {
+        class EndlessPolicy extends LoadBalancingPolicy {
+            *#plan() {
+                for (;;) {
+                    yield* this.hosts.values();
+                }
+            }
+            newQueryPlan(keyspace, executionOptions, callback) {
+                callback(null, this.#plan());
+            }
+        }
+        const policy = new EndlessPolicy();
+        await initPolicy(policy);
+        const plan = policy.getRustConfiguration().queryPlan({
+            consistency: 1,
+            isLwt: false,
+        });
+        assert.deepStrictEqual(plan, ids);
+    });
+
+    it("should return an empty plan when newQueryPlan fails", async () => {
+        class FailingPolicy extends LoadBalancingPolicy {
+            newQueryPlan(keyspace, executionOptions, callback) {
+                callback(new Error("no plan"));
+            }
+        }
+        const policy = new FailingPolicy();
+        await initPolicy(policy);
+        const plan = policy.getRustConfiguration().queryPlan({
+            consistency: 1,
+            isLwt: false,
+        });
+        assert.deepStrictEqual(plan, []);
+    });
+});
@@ -1,5 +1,6 @@
 "use strict";
 
+const { assert } = require("chai");
 const net = require("node:net");
 const rust = require("../../index");
 const { setRustOptions, extend } = require("../../lib/client-options");
@@ -9,8 +10,23 @@ const {
 const {
     DefaultLoadBalancingPolicy,
 } = require("../../lib/policies/load-balancing");
-const { RetryPolicy } = require("../../lib/policies/retry");
-const { Uuid } = require("../../lib/types");
+const {
+    RetryPolicy,
+    FallthroughRetryPolicy,
+    DowngradingConsistencyRetryPolicy,
+    IdempotenceAwareRetryPolicy,
+} = require("../../lib/policies/retry");
+const {
+    ConstantSpeculativeExecutionPolicy,
+} = require("../../lib/policies/speculative-execution");
+const {
+    ExponentialReconnectionPolicy,
+} = require("../../lib/policies/reconnection");
+const {
+    MonotonicTimestampGenerator,
+} = require("../../lib/policies/timestamp-generation");
+const { Uuid, consistencies } = require("../../lib/types");
+const { ExecutionProfile } = require("../../lib/execution-profile");
 const { PlainTextAuthProvider } = require("../../lib/auth");
 
 const resolutionMap = new Map([
@@ -54,9 +70,44 @@ const options = {
             enableShufflingReplicas: false,
             allowList: ["127.0.0.1:7312"],
         }),
-        retry: new RetryPolicy(),
+        retry: new RetryPolicy({ maxRetries: 3 }),
         addressResolution: new MappingAddressTranslator(resolutionMap),
+        speculativeExecution: new ConstantSpeculativeExecutionPolicy(100, 2),
+        timestampGeneration: new MonotonicTimestampGenerator(2000, 500),
+        reconnection: new ExponentialReconnectionPolicy(200, 60000, false),
+    },
+    protocolOptions: {
+        port: 9043,
+        maxSchemaAgreementWaitSeconds: 30,
+        schemaAgreementInterval: 500,
+    },
+    pooling: {
+        heartBeatInterval: 15000,
+        coreConnectionsPerShard: 2,
     },
+    socketOptions: {
+        readTimeout: 4321,
+        connectTimeout: 1234,
+        tcpNoDelay: false,
+        keepAlive: true,
+        keepAliveDelay: 300000,
+    },
+    profiles: [
+        new ExecutionProfile("analytics", {
+            consistency: consistencies.all,
+            serialConsistency: consistencies.localSerial,
+            readTimeout: 30000,
+            loadBalancing: new DefaultLoadBalancingPolicy({
+                preferDatacenter: "Analytics DC",
+            }),
+            retry: new FallthroughRetryPolicy(),
+        }),
+        new ExecutionProfile("idempotent", {
+            retry: new IdempotenceAwareRetryPolicy(
+                new DowngradingConsistencyRetryPolicy(),
+            ),
+        }),
+    ],
 };
 
 // Since some of the options can be represented as multiple types,
@@ -86,4 +137,19 @@ describe("Client options", function () {
     it("should correctly verify empty client options", function () {
         extend({ contactPoints: ["1.1.1.1"] });
     });
+    it("should enable TCP keep-alive with the default options", function () {
+        const rustOptions = setRustOptions(
+            extend({ contactPoints: ["1.1.1.1"] }),
+        );
+        assert.strictEqual(rustOptions.tcpKeepaliveInterval, 60000);
+    });
+    it("should disable TCP keep-alive when keepAlive is false", function () {
+        const rustOptions = setRustOptions(
+            extend({
+                contactPoints: ["1.1.1.1"],
+                socketOptions: { keepAlive: false, keepAliveDelay: 1000 },
+            }),
+        );
+        assert.strictEqual(rustOptions.tcpKeepaliveInterval, undefined);
+    });
 });
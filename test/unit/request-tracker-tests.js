@@ -0,0 +1,181 @@
+"use strict";
+
+const { assert } = require("chai");
+
+const { RequestTracker } = require("../../lib/tracker");
+
+class RecordingTracker extends RequestTracker {
+    constructor() {
+        super();
+        this.calls = [];
+    }
+    onSuccess(
+        host,
+        query,
+        parameters,
+        execOptions,
+        requestLength,
+        responseLength,
+        latency,
+    ) {
+        this.calls.push({
+            kind: "success",
+            host,
+            query,
+            parameters,
+            execOptions,
+            requestLength,
+            responseLength,
+            latency,
+        });
+    }
+    onError(
+        host,
+        query,
+        parameters,
+        execOptions,
+        requestLength,
+        err,
+        latency,
+    ) {
+        this.calls.push({
+            kind: "error",
+            host,
+            query,
+            parameters,
+            execOptions,
+            requestLength,
+            err,
+            latency,
+        });
+    }
+}
+
+function report(overrides) {
+    return Object.assign(
+        {
+            queries: ["SELECT * FROM ks.tbl WHERE id = ?"],
+            isBatch: false,
+            isBatchLogged: false,
+            isPrepared: true,
+            paramsCount: 1,
+            requestLength: 40,
+            responseLength: 100,
+            coordinator: "127.0.0.1:9042",
+            attempts: 1,
+            retries: 0,
+            latency: [0, 1000],
+            error: undefined,
+        },
+        overrides,
+    );
+}
+
+function mockClient(hosts) {
+    const logged = [];
+    return {
+        logged,
+        hosts: new Map(hosts),
+        log: (level, message) => logged.push([level, message]),
+    };
+}
+
+describe("RequestTracker", function () {
+    describe("#getRustConfiguration()", function () {
+        it("should report successful requests to onSuccess", () => {
+            const host = { address: "127.0.0.1:9042" };
+            const client = mockClient([["127.0.0.1:9042", host]]);
+            const tracker = new RecordingTracker();
+
+            tracker.getRustConfiguration(client)([report()]);
+
+            assert.lengthOf(tracker.calls, 1);
+            const call = tracker.calls[0];
+            assert.strictEqual(call.kind, "success");
+            assert.strictEqual(call.host, host);
+            assert.strictEqual(
+                call.query,
+                "SELECT * FROM ks.tbl WHERE id = ?",
+            );
+            assert.isNull(call.parameters);
+            assert.isTrue(call.execOptions.isPrepared());
+            assert.strictEqual(call.execOptions.getParametersCount(), 1);
+            assert.strictEqual(call.requestLength, 40);
+            assert.strictEqual(call.responseLength, 100);
+            assert.deepStrictEqual(call.latency, [0, 1000]);
+        });
+
+        it("should report failed requests to onError", () => {
+            const client = mockClient([]);
+            const tracker = new RecordingTracker();
+            const err = new Error("Test error");
+
+            tracker.getRustConfiguration(client)([
+                report({
+                    coordinator: undefined,
+                    responseLength: undefined,
+                    attempts: 3,
+                    retries: 2,
+                    error: err,
+                }),
+            ]);
+
+            assert.lengthOf(tracker.calls, 1);
+            const call = tracker.calls[0];
+            assert.strictEqual(call.kind, "error");
+            assert.strictEqual(call.err, err);
+            assert.strictEqual(call.host.addressToString(), "unknown host");
+            assert.strictEqual(call.execOptions.getAttempts(), 3);
+            assert.strictEqual(call.execOptions.getRetries(), 2);
+        });
+
+        it("should report batches with the queries of their statements", () => {
+            const client = mockClient([]);
+            const tracker = new RecordingTracker();
+
+            tracker.getRustConfiguration(client)([
+                report({
+                    queries: [
+                        "INSERT INTO ks.tbl (id) VALUES (1)",
+                        "DELETE FROM ks.tbl WHERE id = 2",
+                    ],
+                    isBatch: true,
+                    isBatchLogged: true,
+                    isPrepared: false,
+                    paramsCount: 0,
+                    coordinator: "127.0.0.2:9042",
+                }),
+            ]);
+
+            const call = tracker.calls[0];
+            assert.deepStrictEqual(call.query, [
+                { query: "INSERT INTO ks.tbl (id) VALUES (1)" },
+                { query: "DELETE FROM ks.tbl WHERE id = 2" },
+            ]);
+            assert.isTrue(call.execOptions.isBatchLogged());
+            assert.isFalse(call.execOptions.isPrepared());
+            assert.strictEqual(call.host.addressToString(), "127.0.0.2:9042");
+        });
+
+        it("should log the errors of the tracker and continue with the next reports", () => {
+            const client = mockClient([]);
+            const tracker = new RecordingTracker();
+            let failed = false;
+            const onSuccess = tracker.onSuccess;
+            tracker.onSuccess = function (...args) {
+                if (!failed) {
+                    failed = true;
+                    throw new Error("Tracker failure");
+                }
+                return onSuccess.apply(this, args);
+            };
+
+            tracker.getRustConfiguration(client)([report(), report()]);
+
+            assert.lengthOf(tracker.calls, 1);
+            assert.lengthOf(client.logged, 1);
+            assert.strictEqual(client.logged[0][0], "warning");
+            assert.include(client.logged[0][1], "Tracker failure");
+        });
+    });
+});
@@ -0,0 +1,282 @@
+"use strict";
+
+const { assert } = require("chai");
+
+const rust = require("../../index");
+const errors = require("../../lib/errors");
+const types = require("../../lib/types");
+const {
+    RetryPolicy,
+    DowngradingConsistencyRetryPolicy,
+    FallthroughRetryPolicy,
+    IdempotenceAwareRetryPolicy,
+} = require("../../lib/policies/retry");
+
+const { retryDecision } = RetryPolicy;
+
+/**
+ * Calls the decide callback passed to Rust with a failed attempt.
+ */
+function decide(policy, error, nbRetry = 0, isIdempotent = true) {
+    const config = policy.getRustConfiguration();
+    assert.strictEqual(config.kind, rust.RetryPolicyKind.Custom);
+    return config.decide({ nbRetry, isIdempotent, error });
+}
+
+const readTimeout = {
+    kind: "onReadTimeout",
+    consistency: types.consistencies.quorum,
+    received: 2,
+    blockFor: 2,
+    isDataPresent: false,
+};
+
+describe("RetryPolicy", function () {
+    it("should use the built-in policies when not inherited", function () {
+        assert.deepStrictEqual(new RetryPolicy().getRustConfiguration(), {
+            kind: rust.RetryPolicyKind.Default,
+        });
+        assert.deepStrictEqual(
+            new FallthroughRetryPolicy().getRustConfiguration(),
+            { kind: rust.RetryPolicyKind.Fallthrough },
+        );
+    });
+
+    it("should pass the maximum number of retries to the built-in policy", function () {
+        assert.deepStrictEqual(
+            new RetryPolicy({ maxRetries: 3 }).getRustConfiguration(),
+            { kind: rust.RetryPolicyKind.Default, maxRetries: 3 },
+        );
+        class CustomPolicy extends RetryPolicy {}
+        const policy = new CustomPolicy({ maxRetries: 2 });
+        assert.strictEqual(
+            decide(policy, readTimeout, 1).decision,
+            retryDecision.retry,
+        );
+        assert.strictEqual(
+            decide(policy, readTimeout, 2).decision,
+            retryDecision.rethrow,
+        );
+    });
+
+    it("should pass the details of the error to the overridden method", function () {
+        const calls = [];
+        class CustomPolicy extends RetryPolicy {
+            onWriteTimeout(info, consistency, received, blockFor, writeType) {
+                calls.push([info, consistency, received, blockFor, writeType]);
+                return this.retryResult(types.consistencies.one, false);
+            }
+        }
+        const decision = decide(
+            new CustomPolicy(),
+            {
+                kind: "onWriteTimeout",
+                consistency: types.consistencies.all,
+                received: 1,
+                blockFor: 3,
+                writeType: "SIMPLE",
+            },
+            1,
+            false,
+        );
+        assert.deepStrictEqual(calls, [
+            [
+                { nbRetry: 1, isIdempotent: false },
+                types.consistencies.all,
+                1,
+                3,
+                "SIMPLE",
+            ],
+        ]);
+        assert.deepStrictEqual(decision, {
+            decision: retryDecision.retry,
+            consistency: types.consistencies.one,
+            useCurrentHost: false,
+        });
+    });
+
+    it("should follow the default policy for methods that are not overridden", function () {
+        class CustomPolicy extends RetryPolicy {}
+        const policy = new CustomPolicy();
+        assert.strictEqual(
+            decide(policy, readTimeout).decision,
+            retryDecision.retry,
+        );
+        assert.strictEqual(
+            decide(policy, readTimeout, 1).decision,
+            retryDecision.rethrow,
+        );
+        assert.deepStrictEqual(
+            decide(policy, {
+                kind: "onUnavailable",
+                consistency: types.consistencies.quorum,
+                required: 2,
+                alive: 1,
+            }),
+            {
+                decision: retryDecision.retry,
+                consistency: undefined,
+                useCurrentHost: false,
+            },
+        );
+        const overloaded = new errors.ResponseError(
+            types.responseErrorCodes.overloaded,
+            "Overloaded",
+        );
+        const requestError = {
+            kind: "onRequestError",
+            consistency: types.consistencies.one,
+            error: overloaded,
+        };
+        assert.strictEqual(
+            decide(policy, requestError).decision,
+            retryDecision.retry,
+        );
+        assert.strictEqual(
+            decide(policy, requestError, 0, false).decision,
+            retryDecision.rethrow,
+        );
+        assert.strictEqual(
+            decide(policy, {
+                ...requestError,
+                error: new Error("Serialization failed"),
+            }).decision,
+            retryDecision.rethrow,
+        );
+    });
+
+    it("should rethrow when the policy throws", function () {
+        class CustomPolicy extends RetryPolicy {
+            onReadTimeout() {
+                throw new Error("Policy failure");
+            }
+        }
+        assert.deepStrictEqual(decide(new CustomPolicy(), readTimeout), {
+            decision: retryDecision.rethrow,
+        });
+    });
+});
+
+describe("FallthroughRetryPolicy", function () {
+    it("should rethrow all errors when inherited", function () {
+        class CustomPolicy extends FallthroughRetryPolicy {}
+        assert.strictEqual(
+            decide(new CustomPolicy(), readTimeout).decision,
+            retryDecision.rethrow,
+        );
+    });
+});
+
+describe("DowngradingConsistencyRetryPolicy", function () {
+    it("should use the built-in policy when not inherited", function () {
+        assert.deepStrictEqual(
+            new DowngradingConsistencyRetryPolicy({
+                maxRetries: 2,
+            }).getRustConfiguration(),
+            { kind: rust.RetryPolicyKind.DowngradingConsistency, maxRetries: 2 },
+        );
+    });
+
+    it("should retry with the consistency achieved by the replicas when inherited", function () {
+        class CustomPolicy extends DowngradingConsistencyRetryPolicy {}
+        const policy = new CustomPolicy();
+        assert.deepStrictEqual(
+            decide(policy, {
+                kind: "onUnavailable",
+                consistency: types.consistencies.all,
+                required: 3,
+                alive: 2,
+            }),
+            {
+                decision: retryDecision.retry,
+                consistency: types.consistencies.two,
+                useCurrentHost: true,
+            },
+        );
+        assert.deepStrictEqual(
+            decide(policy, { ...readTimeout, received: 1 }).consistency,
+            types.consistencies.one,
+        );
+        assert.strictEqual(
+            decide(policy, {
+                kind: "onWriteTimeout",
+                consistency: types.consistencies.quorum,
+                received: 1,
+                blockFor: 2,
+                writeType: "SIMPLE",
+            }).decision,
+            retryDecision.ignore,
+        );
+        assert.strictEqual(
+            decide(policy, {
+                ...readTimeout,
+                consistency: types.consistencies.serial,
+            }).decision,
+            retryDecision.rethrow,
+        );
+    });
+});
+
+describe("IdempotenceAwareRetryPolicy", function () {
+    it("should wrap the built-in child policy", function () {
+        assert.deepStrictEqual(
+            new IdempotenceAwareRetryPolicy().getRustConfiguration(),
+            {
+                kind: rust.RetryPolicyKind.IdempotenceAware,
+                childKind: rust.RetryPolicyKind.Default,
+                maxRetries: undefined,
+            },
+        );
+        const policy = new IdempotenceAwareRetryPolicy(
+            new IdempotenceAwareRetryPolicy(
+                new DowngradingConsistencyRetryPolicy({ maxRetries: 2 }),
+            ),
+        );
+        assert.deepStrictEqual(policy.getRustConfiguration(), {
+            kind: rust.RetryPolicyKind.IdempotenceAware,
+            childKind: rust.RetryPolicyKind.DowngradingConsistency,
+            maxRetries: 2,
+        });
+    });
+
+    it("should wrap the custom child policy", function () {
+        class CustomPolicy extends RetryPolicy {
+            onWriteTimeout() {
+                return this.retryResult();
+            }
+        }
+        const config = new IdempotenceAwareRetryPolicy(
+            new CustomPolicy(),
+        ).getRustConfiguration();
+        assert.strictEqual(
+            config.kind,
+            rust.RetryPolicyKind.IdempotenceAware,
+        );
+        assert.strictEqual(config.childKind, rust.RetryPolicyKind.Custom);
+        assert.isFunction(config.decide);
+    });
+
+    it("should rethrow write timeouts of requests that are not idempotent when inherited", function () {
+        class CustomPolicy extends IdempotenceAwareRetryPolicy {}
+        const policy = new CustomPolicy(new DowngradingConsistencyRetryPolicy());
+        const writeTimeout = {
+            kind: "onWriteTimeout",
+            consistency: types.consistencies.quorum,
+            received: 0,
+            blockFor: 2,
+            writeType: "BATCH_LOG",
+        };
+        assert.strictEqual(
+            decide(policy, writeTimeout, 0, true).decision,
+            retryDecision.retry,
+        );
+        assert.strictEqual(
+            decide(policy, writeTimeout, 0, false).decision,
+            retryDecision.rethrow,
+        );
+        assert.strictEqual(
+            decide(policy, readTimeout, 0, false).decision,
+            retryDecision.retry,
+        );
+    });
+});
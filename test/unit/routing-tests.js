@@ -0,0 +1,70 @@
+"use strict";
+const { assert } = require("chai");
+const rust = require("../../index");
+const errors = require("../../lib/errors");
+
+describe("Routing of unprepared requests", function () {
+    const test = Buffer.from("test");
+    const other = Buffer.from("other");
+
+    it("should compute the Murmur3 token of the routing key", function () {
+        assert.strictEqual(
+            rust.testsRoutingToken({ routingKey: [test] }, []),
+            -6017608668500074083n,
+        );
+    });
+
+    it("should compute the token from the routing indexes", function () {
+        assert.strictEqual(
+            rust.testsRoutingToken({ routingIndexes: [1] }, [other, test]),
+            -6017608668500074083n,
+        );
+    });
+
+    // Composite keys are built the same way from the routing key parts and the parameters
+    it("should compute the token of a composite routing key", function () {
+        const fromKey = rust.testsRoutingToken(
+            { routingKey: [test, other] },
+            [],
+        );
+        const fromIndexes = rust.testsRoutingToken(
+            { routingIndexes: [1, 0] },
+            [other, test],
+        );
+        assert.typeOf(fromKey, "bigint");
+        assert.strictEqual(fromKey, fromIndexes);
+        assert.notStrictEqual(
+            fromKey,
+            rust.testsRoutingToken({ routingKey: [other, test] }, []),
+        );
+    });
+
+    it("should prefer the routing key over the routing indexes", function () {
+        assert.strictEqual(
+            rust.testsRoutingToken(
+                { routingKey: [test], routingIndexes: [0] },
+                [other],
+            ),
+            -6017608668500074083n,
+        );
+    });
+
+    it("should not route when the routing key cannot be built", function () {
+        assert.isNull(rust.testsRoutingToken({}, [test]));
+        assert.isNull(rust.testsRoutingToken({ routingIndexes: [2] }, [test]));
+        assert.isNull(
+            rust.testsRoutingToken({ routingIndexes: [0, 1] }, [test, null]),
+        );
+        assert.isNull(
+            rust.testsRoutingToken({ routingKey: [test, undefined] }, []),
+        );
+    });
+
+    // Unprepared statements have no named parameters to look the names up in
+    it("should reject routing names", function () {
+        assert.throws(
+            () => rust.testsRoutingToken({ routingNames: ["a"] }, [test]),
+            errors.ArgumentError,
+        );
+    });
+});